@@ -28,6 +28,7 @@ Commands:
     fmt           format code, fail if bad
     test          run unit tests (no Godot needed)
     itest         run integration tests (from within Godot)
+    bench         run integration tests and benchmarks, without godot_await's optional tracking
     clippy        validate clippy lints
     klippy        validate + fix clippy
     doc           generate docs for 'godot' crate
@@ -38,6 +39,9 @@ Options:
     --double                 run check with double-precision
     -f, --filter <arg>       only run integration tests which contain any of the
                              args (comma-separated). requires itest.
+    -r, --report <dir>       write JUnit XML and JSON reports of the integration
+                             tests to <dir>, and bench.json with bench. requires itest
+                             or bench.
     -a, --api-version <ver>  specify the Godot API version to use (e.g. 4.3, 4.3.1).
 
 Examples:
@@ -161,7 +165,16 @@ function cmd_clippy() {
         -D clippy::todo \
         -D clippy::unimplemented \
         -D warnings \
-        -A clippy::precedence
+        -A clippy::precedence && \
+        cmd_clippy_await
+}
+
+# The workspace build above only covers the godot_await features itest uses; also check it without optional features,
+# with its default features and with all of them.
+function cmd_clippy_await() {
+    run cargo clippy -p godot_await --all-targets --no-default-features "${godotCargoArgs[@]}" -- -D warnings -A clippy::precedence && \
+        run cargo build -p godot_await "${godotCargoArgs[@]}" && \
+        run cargo clippy -p godot_await --all-targets --all-features "${godotCargoArgs[@]}" -- -D warnings -A clippy::precedence
 }
 
 function cmd_klippy() {
@@ -180,9 +193,16 @@ function cmd_klippy() {
 }
 
 function cmd_itest() {
+    findGodot && \
+        run cargo build -p itest "${extraCargoArgs[@]}" --features itest/await-instrumentation && \
+        run "$godotBin" $GODOT_ARGS --path itest/godot --headless --fixed-fps 64 -- "[${extraArgs[@]}]" "${reportArgs[@]}"
+}
+
+# Benchmarks are measured without godot_await's optional tracking (`diagnostics`, `registry`, `editor`).
+function cmd_bench() {
     findGodot && \
         run cargo build -p itest "${extraCargoArgs[@]}" && \
-        run "$godotBin" $GODOT_ARGS --path itest/godot --headless -- "[${extraArgs[@]}]"
+        run "$godotBin" $GODOT_ARGS --path itest/godot --headless --fixed-fps 64 -- "[${extraArgs[@]}]" "${benchArgs[@]}"
 }
 
 function cmd_doc() {
@@ -200,8 +220,12 @@ function cmd_dok() {
 # By default, disable `codegen-full` to reduce compile times and prevent flip-flopping between
 # `itest` compilations and `check.sh` runs. Note that this means some runs are different from CI.
 extraCargoArgs=("--no-default-features")
+# Subset of extraCargoArgs forwarded to the `godot` crate, for commands building godot_await on its own.
+godotCargoArgs=()
 cmds=()
 extraArgs=()
+reportArgs=()
+benchArgs=()
 apiVersion=""
 
 while [[ $# -gt 0 ]]; do
@@ -216,8 +240,9 @@ while [[ $# -gt 0 ]]; do
             ;;
         --double)
             extraCargoArgs+=("--features" "godot/double-precision")
+            godotCargoArgs+=("--features" "godot/double-precision")
             ;;
-        fmt | itest | clippy | klippy | doc | dok)
+        fmt | itest | bench | clippy | klippy | doc | dok)
             cmds+=("$arg")
             ;;
         -f | --filter)
@@ -234,6 +259,23 @@ while [[ $# -gt 0 ]]; do
                 exit 2
             fi
             ;;
+        -r | --report)
+            if [[ "${cmds[*]}" =~ itest|bench ]]; then
+                if [[ -z "$2" ]]; then
+                    log "-r/--report requires an argument."
+                    exit 2
+                fi
+
+                reportDir="$(realpath -m "$2")"
+                mkdir -p "$reportDir"
+                reportArgs+=("--junit-xml=$reportDir/itest.xml" "--report-json=$reportDir/itest.json")
+                benchArgs+=("--bench-json=$reportDir/bench.json")
+                shift
+            else
+                log "-r/--report requires 'itest' or 'bench' to be specified as a command."
+                exit 2
+            fi
+            ;;
         -a | --api-version)
             if [[ -z "$2" || "$2" == -* ]]; then
                 log "-a/--api-version requires an argument."
@@ -245,6 +287,7 @@ while [[ $# -gt 0 ]]; do
 
             apiFeature=$(version_to_feature "$apiVersion")
             extraCargoArgs+=("--features" "$apiFeature")
+            godotCargoArgs+=("--features" "$apiFeature")
 
             log "Using Godot API version $apiVersion with feature $apiFeature"
 
@@ -0,0 +1,100 @@
+//! Abstractions over signal sources and the clock, so gameplay flows can be tested without Godot.
+//!
+//! Write the flow against [`Clock`] and [`SignalSource`], pass [`GodotClock`] and Ext trait closures in the game,
+//! and the deterministic mocks of the `mock` feature in `cargo test`.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! async fn open_door(clock: impl Clock, pressed: impl SignalSource<Output = ()>) -> &'static str {
+//!     pressed.next().await;
+//!     clock.sleep(1.0).await;
+//!     "open"
+//! }
+//!
+//! // in game
+//! task::spawn(async move {
+//!     open_door(GodotClock, || button.pressed()).await;
+//! });
+//! ```
+
+use std::future::Future;
+
+use godot::task::SignalFuture;
+
+use crate::scheduler::{self, TimerMode};
+use crate::wait::wait;
+
+/// Source of time: resolves sleeps and reports the current time.
+pub trait Clock {
+    /// Future returned by [`sleep`](Self::sleep).
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a future resolving after `time_sec` seconds.
+    fn sleep(&self, time_sec: f64) -> Self::Sleep;
+
+    /// Current time in seconds, from an arbitrary origin.
+    fn now(&self) -> f64;
+}
+
+/// Source of signal emissions.
+///
+/// Implemented for every `Fn() -> impl Future`, so Ext trait methods can be passed as closures, e.g. `|| button.pressed()`.
+pub trait SignalSource {
+    /// Arguments of the signal.
+    type Output;
+    /// Future returned by [`next`](Self::next).
+    type Future: Future<Output = Self::Output>;
+
+    /// Returns a future resolving at the next emission of the signal.
+    fn next(&self) -> Self::Future;
+}
+
+impl<F, Fut> SignalSource for F
+where
+    F: Fn() -> Fut,
+    Fut: Future,
+{
+    type Output = Fut::Output;
+    type Future = Fut;
+
+    #[inline]
+    fn next(&self) -> Self::Future {
+        self()
+    }
+}
+
+/// [`Clock`] of the running game: sleeps with [`wait()`], and reads the game clock the sleeps advance with,
+/// so both follow pausing and `Engine.time_scale` alike.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GodotClock;
+
+impl Clock for GodotClock {
+    type Sleep = SignalFuture<()>;
+
+    #[inline]
+    #[track_caller]
+    fn sleep(&self, time_sec: f64) -> Self::Sleep {
+        wait(time_sec)
+    }
+
+    #[inline]
+    fn now(&self) -> f64 {
+        scheduler::elapsed(TimerMode::default())
+    }
+}
+
+impl<C: Clock> Clock for &C {
+    type Sleep = C::Sleep;
+
+    #[inline]
+    #[track_caller]
+    fn sleep(&self, time_sec: f64) -> Self::Sleep {
+        (**self).sleep(time_sec)
+    }
+
+    #[inline]
+    fn now(&self) -> f64 {
+        (**self).now()
+    }
+}
@@ -0,0 +1,414 @@
+//! Cancellation tokens and abortable futures.
+//!
+//! Godot tasks run on the main thread, so everything here is `Rc`-based and not `Send`.
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker, ready};
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::rc::{Rc, Weak};
+
+use pin_project_lite::pin_project;
+
+use crate::future::Future;
+
+/// Error returned by [`WithCancel`] when its [`CancellationToken`] was cancelled first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("future was cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+/// Error returned by [`Abortable`] when its [`AbortHandle`] was aborted first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aborted;
+
+impl fmt::Display for Aborted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("future was aborted")
+    }
+}
+
+impl Error for Aborted {}
+
+/// Wakers of the futures waiting on a token, keyed per future so each can remove its own on drop.
+#[derive(Default)]
+struct WakerSlab {
+    entries: Vec<Option<Waker>>,
+    free: Vec<usize>,
+}
+
+impl WakerSlab {
+    fn insert(&mut self, waker: Waker) -> usize {
+        match self.free.pop() {
+            Some(key) => {
+                self.entries[key] = Some(waker);
+                key
+            }
+            None => {
+                self.entries.push(Some(waker));
+                self.entries.len() - 1
+            }
+        }
+    }
+
+    fn remove(&mut self, key: usize) {
+        if let Some(entry) = self.entries.get_mut(key) {
+            *entry = None;
+            self.free.push(key);
+        }
+    }
+}
+
+#[derive(Default)]
+struct TokenState {
+    cancelled: Cell<bool>,
+    wakers: RefCell<WakerSlab>,
+    children: RefCell<Vec<Weak<TokenState>>>,
+}
+
+impl TokenState {
+    fn cancel(&self) {
+        if self.cancelled.replace(true) {
+            return;
+        }
+
+        // The keys stay taken until their futures are dropped, nothing registers after cancellation.
+        let wakers: Vec<Waker> = self
+            .wakers
+            .borrow_mut()
+            .entries
+            .iter_mut()
+            .filter_map(Option::take)
+            .collect();
+        for waker in wakers {
+            waker.wake();
+        }
+        for child in self.children.take() {
+            if let Some(child) = child.upgrade() {
+                child.cancel();
+            }
+        }
+    }
+
+    /// Registers the waker of the future holding `key`, returning the key to remove it with.
+    fn register(&self, key: Option<usize>, waker: &Waker) -> usize {
+        let mut wakers = self.wakers.borrow_mut();
+        match key {
+            Some(key) => {
+                match &mut wakers.entries[key] {
+                    Some(current) if current.will_wake(waker) => {}
+                    current => *current = Some(waker.clone()),
+                }
+                key
+            }
+            None => wakers.insert(waker.clone()),
+        }
+    }
+
+    fn unregister(&self, key: usize) {
+        self.wakers.borrow_mut().remove(key);
+    }
+}
+
+/// A token which can be used to signal cancellation to any number of futures.
+///
+/// Clones share the same state. Child tokens are cancelled together with their parent,
+/// but cancelling a child does not affect the parent.
+///
+/// # Examples
+///
+/// ```
+/// use godot_await::cancel::CancellationToken;
+/// use godot_await::future::{self, pending, FutureExt};
+///
+/// # spin_on::spin_on(async {
+/// let token = CancellationToken::new();
+/// let child = token.child_token();
+///
+/// token.cancel();
+/// assert!(child.is_cancelled());
+/// assert!(pending::<()>().with_cancel(&child).await.is_err());
+///
+/// // Race a cancellation against any other future with `or`.
+/// future::or(pending(), token.cancelled()).await;
+/// # })
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Rc<TokenState>,
+}
+
+impl CancellationToken {
+    /// Creates a new, uncancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a token which is cancelled when `self` is cancelled.
+    pub fn child_token(&self) -> Self {
+        let child = Self::new();
+        if self.is_cancelled() {
+            child.state.cancelled.set(true);
+        } else {
+            let mut children = self.state.children.borrow_mut();
+            children.retain(|c| c.strong_count() > 0);
+            children.push(Rc::downgrade(&child.state));
+        }
+        child
+    }
+
+    /// Cancels this token and all of its children, waking every future waiting on them.
+    pub fn cancel(&self) {
+        self.state.cancel();
+    }
+
+    /// Returns `true` if this token (or one of its parents) was cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.get()
+    }
+
+    /// Returns a future which resolves once this token is cancelled.
+    ///
+    /// Its output is `()`, so it can be raced against signal futures with [`or()`](crate::future::or).
+    pub fn cancelled(&self) -> WaitForCancellation {
+        WaitForCancellation {
+            token: self.clone(),
+            key: None,
+        }
+    }
+}
+
+impl fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("is_cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+/// Future for the [`CancellationToken::cancelled()`] method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitForCancellation {
+    token: CancellationToken,
+    /// Key of the registered waker in the token's slab.
+    key: Option<usize>,
+}
+
+impl WaitForCancellation {
+    fn unregister(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.token.state.unregister(key);
+        }
+    }
+}
+
+impl Future for WaitForCancellation {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_cancelled() {
+            self.unregister();
+            return Poll::Ready(());
+        }
+        let key = self.token.state.register(self.key, cx.waker());
+        self.key = Some(key);
+        Poll::Pending
+    }
+}
+
+impl Drop for WaitForCancellation {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+pin_project! {
+    /// Future for the [`FutureExt::with_cancel()`](crate::future::FutureExt::with_cancel) method.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithCancel<F> {
+        #[pin]
+        inner: F,
+        cancelled: WaitForCancellation,
+    }
+}
+
+impl<F> WithCancel<F> {
+    pub(crate) fn new(inner: F, token: &CancellationToken) -> Self {
+        WithCancel {
+            inner,
+            cancelled: token.cancelled(),
+        }
+    }
+}
+
+impl<F: Future> Future for WithCancel<F> {
+    type Output = Result<F::Output, Cancelled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if Pin::new(&mut *this.cancelled).poll(cx).is_ready() {
+            return Poll::Ready(Err(Cancelled));
+        }
+        let output = ready!(this.inner.poll(cx));
+        this.cancelled.unregister();
+        Poll::Ready(Ok(output))
+    }
+}
+
+/// A handle to an [`Abortable`] future.
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    token: CancellationToken,
+}
+
+/// A registration handle for an [`Abortable`] future, created by [`AbortHandle::new_pair()`].
+#[derive(Debug)]
+pub struct AbortRegistration {
+    token: CancellationToken,
+}
+
+impl AbortHandle {
+    /// Creates an `AbortHandle` and an `AbortRegistration` to be passed to [`Abortable::new()`].
+    pub fn new_pair() -> (Self, AbortRegistration) {
+        let token = CancellationToken::new();
+        (
+            AbortHandle {
+                token: token.clone(),
+            },
+            AbortRegistration { token },
+        )
+    }
+
+    /// Aborts the associated future. It resolves to `Err(Aborted)` the next time it is polled.
+    pub fn abort(&self) {
+        self.token.cancel();
+    }
+
+    /// Returns `true` if [`abort()`](Self::abort) was called.
+    #[inline]
+    pub fn is_aborted(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+pin_project! {
+    /// A future which can be remotely aborted through an [`AbortHandle`].
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Abortable<F> {
+        #[pin]
+        inner: WithCancel<F>,
+    }
+}
+
+impl<F> Abortable<F> {
+    /// Wraps `future` so that it can be aborted through the handle paired with `registration`.
+    pub fn new(future: F, registration: AbortRegistration) -> Self {
+        Abortable {
+            inner: WithCancel::new(future, &registration.token),
+        }
+    }
+}
+
+impl<F: Future> Future for Abortable<F> {
+    type Output = Result<F::Output, Aborted>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project()
+            .inner
+            .poll(cx)
+            .map(|r| r.map_err(|_| Aborted))
+    }
+}
+
+/// Creates a new [`Abortable`] future and an [`AbortHandle`] which can be used to stop it.
+///
+/// # Examples
+///
+/// ```
+/// use godot_await::cancel::{abortable, Aborted};
+/// use godot_await::future::pending;
+///
+/// # spin_on::spin_on(async {
+/// let (future, handle) = abortable(pending::<()>());
+/// handle.abort();
+///
+/// assert_eq!(future.await, Err(Aborted));
+/// # })
+/// ```
+pub fn abortable<F: Future>(future: F) -> (Abortable<F>, AbortHandle) {
+    let (handle, registration) = AbortHandle::new_pair();
+    (Abortable::new(future, registration), handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::future::{pending, ready};
+
+    fn registered(token: &CancellationToken) -> usize {
+        let wakers = token.state.wakers.borrow();
+        wakers
+            .entries
+            .iter()
+            .filter(|entry| entry.is_some())
+            .count()
+    }
+
+    fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        Pin::new(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    #[test]
+    fn dropped_futures_unregister() {
+        let token = CancellationToken::new();
+        for _ in 0..100 {
+            let mut cancelled = token.cancelled();
+            assert!(poll_once(&mut cancelled).is_pending());
+            assert_eq!(registered(&token), 1);
+        }
+        assert_eq!(registered(&token), 0);
+        assert_eq!(token.state.wakers.borrow().entries.len(), 1);
+    }
+
+    #[test]
+    fn completed_with_cancel_unregisters() {
+        let token = CancellationToken::new();
+        let mut with_cancel = Box::pin(WithCancel::new(ready(1), &token));
+        assert_eq!(
+            with_cancel
+                .as_mut()
+                .poll(&mut Context::from_waker(Waker::noop())),
+            Poll::Ready(Ok(1))
+        );
+        assert_eq!(registered(&token), 0);
+
+        let mut pending = Box::pin(WithCancel::new(pending::<()>(), &token));
+        assert!(
+            pending
+                .as_mut()
+                .poll(&mut Context::from_waker(Waker::noop()))
+                .is_pending()
+        );
+        assert_eq!(registered(&token), 1);
+        token.cancel();
+        assert_eq!(registered(&token), 0);
+        assert_eq!(
+            pending
+                .as_mut()
+                .poll(&mut Context::from_waker(Waker::noop())),
+            Poll::Ready(Err(Cancelled))
+        );
+    }
+}
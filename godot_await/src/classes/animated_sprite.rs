@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::{AnimatedSprite2D, AnimatedSprite3D, Node};
 
 trait AnimatedSpriteSignal: Inherits<Node> {}
@@ -35,43 +36,66 @@ where
     T: AnimatedSpriteSignal,
 {
     #[inline]
+    #[track_caller]
     fn animation_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "animation_changed").to_future::<()>()
+        object_signal(self, "animation_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn animation_finished(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "animation_finished").to_future::<()>()
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check(
+            self,
+            "animation_finished",
+            crate::diagnostics::animated_sprite_check,
+        );
+        object_signal(self, "animation_finished").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn animation_looped(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "animation_looped").to_future::<()>()
+        object_signal(self, "animation_looped").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn frame_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "frame_changed").to_future::<()>()
+        object_signal(self, "frame_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn sprite_frames_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "sprite_frames_changed").to_future::<()>()
+        object_signal(self, "sprite_frames_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn animation_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "animation_changed").to_fallible_future::<()>()
+        object_signal(self, "animation_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn animation_finished_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "animation_finished").to_fallible_future::<()>()
+        let finished = object_signal(self, "animation_finished").to_fallible_future::<()>();
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check_fallible(
+            self,
+            "animation_finished",
+            crate::diagnostics::animated_sprite_check,
+        );
+        finished
     }
     #[inline]
+    #[track_caller]
     fn animation_looped_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "animation_looped").to_fallible_future::<()>()
+        object_signal(self, "animation_looped").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn frame_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "frame_changed").to_fallible_future::<()>()
+        object_signal(self, "frame_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn sprite_frames_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "sprite_frames_changed").to_fallible_future::<()>()
+        object_signal(self, "sprite_frames_changed").to_fallible_future::<()>()
     }
 }
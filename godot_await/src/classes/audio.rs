@@ -1,5 +1,15 @@
-use crate::{Gd, Inherits, Signal, SignalFuture};
-use godot::classes::{AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, Node};
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+use std::future::Future;
+
+use crate::utils::{next_process_frame, object_signal};
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::StringName;
+use godot::classes::{
+    AudioServer, AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, Node, Object,
+};
+use godot::meta::ToGodot;
+use godot::task::FallibleSignalFutureError;
 
 trait AudioStreamPlayerSignal: Inherits<Node> {}
 
@@ -13,6 +23,43 @@ where
 {
     /// Emitted when the audio stops playing.
     fn finished(&self) -> SignalFuture<()>;
+    /// `finished`fallible
+    fn finished_fallible(&self) -> FallibleSignalFuture<()>;
+
+    /// Plays the sound from `from_position` (in seconds), and resolves when that playback finishes or is stopped.
+    ///
+    /// Unlike `finished`, this also resolves after `stop()`, at the next process frame. A paused playback
+    /// (`stream_paused`, or the tree paused while the player cannot process) is still waited for.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// voice_line.play_and_wait(0.0).await;
+    /// dialog.next();
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if the player is freed before
+    fn play_and_wait(&self, from_position: f32) -> PlayAndWait;
+    /// `play_and_wait`fallible
+    fn play_and_wait_fallible(&self, from_position: f32) -> PlayAndWaitFallible;
+    /// Resolves to the playback position once it reaches `position_sec`, e.g. to sync gameplay to the beats of the music.
+    ///
+    /// The position is checked every process frame, and corrected by the time since the last mix and the output latency
+    /// of the `AudioServer`. Resolves right away if the position is already there.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// for beat in 1..=16 {
+    ///     music.wait_playback_position(beat as f64 * 60.0 / BPM).await;
+    ///     pulse_lights();
+    /// }
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if the player is freed before
+    fn wait_playback_position(&self, position_sec: f64) -> PlaybackPosition;
+    /// `wait_playback_position`fallible
+    fn wait_playback_position_fallible(&self, position_sec: f64) -> PlaybackPositionFallible;
 }
 
 impl<T> AudioStreamPlayerExt<T> for Gd<T>
@@ -20,7 +67,231 @@ where
     T: AudioStreamPlayerSignal,
 {
     #[inline]
+    #[track_caller]
     fn finished(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "finished").to_future::<()>()
+        object_signal(self, "finished").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn finished_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "finished").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn play_and_wait(&self, from_position: f32) -> PlayAndWait {
+        PlayAndWait::new(self.clone().upcast(), from_position)
+    }
+    #[inline]
+    #[track_caller]
+    fn play_and_wait_fallible(&self, from_position: f32) -> PlayAndWaitFallible {
+        PlayAndWaitFallible(PlayAndWait::new(self.clone().upcast(), from_position))
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_playback_position(&self, position_sec: f64) -> PlaybackPosition {
+        PlaybackPosition::new(self.clone().upcast(), position_sec)
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_playback_position_fallible(&self, position_sec: f64) -> PlaybackPositionFallible {
+        PlaybackPositionFallible(PlaybackPosition::new(self.clone().upcast(), position_sec))
+    }
+}
+
+pub trait AudioServerExt<T>
+where
+    T: Inherits<AudioServer>,
+{
+    /// Emitted when an audio bus is added, deleted, or moved.
+    fn bus_layout_changed(&self) -> SignalFuture<()>;
+    /// Emitted when the audio bus at `bus_index` is renamed from `old_name` to `new_name`.
+    /// # Return
+    /// (`bus_index`: i64, `old_name`: StringName, `new_name`: StringName,)
+    fn bus_renamed(&self) -> SignalFuture<(i64, StringName, StringName)>;
+    /// `bus_layout_changed`fallible
+    fn bus_layout_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `bus_renamed`fallible
+    fn bus_renamed_fallible(&self) -> FallibleSignalFuture<(i64, StringName, StringName)>;
+}
+
+impl<T> AudioServerExt<T> for Gd<T>
+where
+    T: Inherits<AudioServer>,
+{
+    #[inline]
+    #[track_caller]
+    fn bus_layout_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "bus_layout_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn bus_renamed(&self) -> SignalFuture<(i64, StringName, StringName)> {
+        object_signal(self, "bus_renamed").to_future::<(i64, StringName, StringName)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn bus_layout_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "bus_layout_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn bus_renamed_fallible(&self) -> FallibleSignalFuture<(i64, StringName, StringName)> {
+        object_signal(self, "bus_renamed").to_fallible_future::<(i64, StringName, StringName)>()
+    }
+}
+
+fn is_playing(player: &Gd<Node>) -> bool {
+    player
+        .clone()
+        .upcast::<Object>()
+        .call("is_playing", &[])
+        .to::<bool>()
+}
+
+/// `is_playing()` is also false while the playback is paused, by `stream_paused` or by pausing the tree.
+/// `can_process()` is only meaningful inside the tree, a player outside of it never plays.
+fn is_paused(player: &Gd<Node>) -> bool {
+    (player.is_inside_tree() && !player.can_process())
+        || player
+            .clone()
+            .upcast::<Object>()
+            .call("get_stream_paused", &[])
+            .to::<bool>()
+}
+
+/// Future for `AudioStreamPlayerExt::play_and_wait`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PlayAndWait {
+    player: Gd<Node>,
+    finished: FallibleSignalFuture<()>,
+    next_frame: Option<SignalFuture<()>>,
+}
+
+impl PlayAndWait {
+    #[track_caller]
+    fn new(player: Gd<Node>, from_position: f32) -> Self {
+        let finished = object_signal(&player, "finished").to_fallible_future::<()>();
+        player
+            .clone()
+            .upcast::<Object>()
+            .call("play", &[from_position.to_variant()]);
+        PlayAndWait {
+            player,
+            finished,
+            next_frame: None,
+        }
+    }
+
+    fn poll_end(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), FallibleSignalFutureError>> {
+        loop {
+            if let Poll::Ready(result) = Pin::new(&mut self.finished).poll(cx) {
+                return Poll::Ready(result);
+            }
+            if !self.player.is_instance_valid() {
+                return Poll::Ready(Err(FallibleSignalFutureError));
+            }
+
+            // `stop()` emits no signal, so the player is checked at the end of every frame instead.
+            let next_frame = self.next_frame.get_or_insert_with(next_process_frame);
+            ready!(Pin::new(next_frame).poll(cx));
+            self.next_frame = None;
+            if self.player.is_instance_valid()
+                && !is_playing(&self.player)
+                && !is_paused(&self.player)
+            {
+                return Poll::Ready(Ok(()));
+            }
+        }
+    }
+}
+
+impl Future for PlayAndWait {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_end(cx).map(|result| {
+            result.expect("ERR(godot_await):AudioStreamPlayer was freed before the playback ended")
+        })
+    }
+}
+
+/// Future for `AudioStreamPlayerExt::play_and_wait_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PlayAndWaitFallible(PlayAndWait);
+
+impl Future for PlayAndWaitFallible {
+    type Output = Result<(), FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_end(cx)
+    }
+}
+
+/// Future for `AudioStreamPlayerExt::wait_playback_position`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PlaybackPosition {
+    player: Gd<Node>,
+    position_sec: f64,
+    next_frame: Option<SignalFuture<()>>,
+}
+
+impl PlaybackPosition {
+    fn new(player: Gd<Node>, position_sec: f64) -> Self {
+        PlaybackPosition {
+            player,
+            position_sec,
+            next_frame: None,
+        }
+    }
+
+    fn poll_position(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<f64, FallibleSignalFutureError>> {
+        loop {
+            if !self.player.is_instance_valid() {
+                return Poll::Ready(Err(FallibleSignalFutureError));
+            }
+
+            let server = AudioServer::singleton();
+            let position = self
+                .player
+                .clone()
+                .upcast::<Object>()
+                .call("get_playback_position", &[])
+                .to::<f64>()
+                + server.get_time_since_last_mix()
+                - server.get_output_latency();
+            if position >= self.position_sec {
+                return Poll::Ready(Ok(position));
+            }
+
+            let next_frame = self.next_frame.get_or_insert_with(next_process_frame);
+            ready!(Pin::new(next_frame).poll(cx));
+            self.next_frame = None;
+        }
+    }
+}
+
+impl Future for PlaybackPosition {
+    type Output = f64;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_position(cx).map(|result| {
+            result
+                .expect("ERR(godot_await):AudioStreamPlayer was freed before reaching the position")
+        })
+    }
+}
+
+/// Future for `AudioStreamPlayerExt::wait_playback_position_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PlaybackPositionFallible(PlaybackPosition);
+
+impl Future for PlaybackPositionFallible {
+    type Output = Result<f64, FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_position(cx)
     }
 }
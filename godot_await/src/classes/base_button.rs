@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::BaseButton;
 
 pub trait BaseButtonExt<T>
@@ -33,35 +34,43 @@ where
     T: Inherits<BaseButton>,
 {
     #[inline]
+    #[track_caller]
     fn button_down(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "button_down").to_future::<()>()
+        object_signal(self, "button_down").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn button_up(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "button_up").to_future::<()>()
+        object_signal(self, "button_up").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn pressed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "pressed").to_future::<()>()
+        object_signal(self, "pressed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn toggled(&self) -> SignalFuture<(bool,)> {
-        Signal::from_object_signal(self, "toggled").to_future::<(bool,)>()
+        object_signal(self, "toggled").to_future::<(bool,)>()
     }
     #[inline]
+    #[track_caller]
     fn button_down_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "button_down").to_fallible_future::<()>()
+        object_signal(self, "button_down").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn button_up_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "button_up").to_fallible_future::<()>()
+        object_signal(self, "button_up").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn pressed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "pressed").to_fallible_future::<()>()
+        object_signal(self, "pressed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn toggled_fallible(&self) -> FallibleSignalFuture<(bool,)> {
-        Signal::from_object_signal(self, "toggled").to_fallible_future::<(bool,)>()
+        object_signal(self, "toggled").to_fallible_future::<(bool,)>()
     }
 }
@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::CanvasItem;
 
 pub trait CanvasItemExt<T>
@@ -30,35 +31,43 @@ where
     T: Inherits<CanvasItem>,
 {
     #[inline]
+    #[track_caller]
     fn draw(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "draw").to_future::<()>()
+        object_signal(self, "draw").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn hidden(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "hidden").to_future::<()>()
+        object_signal(self, "hidden").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn item_rect_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "item_rect_changed").to_future::<()>()
+        object_signal(self, "item_rect_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn visibility_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "visibility_changed").to_future::<()>()
+        object_signal(self, "visibility_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn draw_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "draw").to_fallible_future::<()>()
+        object_signal(self, "draw").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn hidden_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "hidden").to_fallible_future::<()>()
+        object_signal(self, "hidden").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn item_rect_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "item_rect_changed").to_fallible_future::<()>()
+        object_signal(self, "item_rect_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn visibility_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "visibility_changed").to_fallible_future::<()>()
+        object_signal(self, "visibility_changed").to_fallible_future::<()>()
     }
 }
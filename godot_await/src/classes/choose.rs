@@ -0,0 +1,29 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::{FallibleSignalFuture, SignalFuture};
+use godot::task::FallibleSignalFutureError;
+
+/// Future resolving to the index of a selected item, e.g. for `OptionButtonExt::choose`,
+/// `ItemListExt::choose` and `TabBarExt::choose`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Choose<F>(pub(crate) F);
+
+impl Future for Choose<SignalFuture<(i64,)>> {
+    type Output = i64;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|(index,)| index)
+    }
+}
+
+impl Future for Choose<FallibleSignalFuture<(i64,)>> {
+    type Output = Result<i64, FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.map(|(index,)| index))
+    }
+}
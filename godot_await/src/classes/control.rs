@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::{Control, InputEvent};
 
 pub trait ControlExt<T>
@@ -49,75 +50,93 @@ where
     T: Inherits<Control>,
 {
     #[inline]
+    #[track_caller]
     fn focus_entered(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "focus_entered").to_future::<()>()
+        object_signal(self, "focus_entered").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn focus_exited(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "focus_exited").to_future::<()>()
+        object_signal(self, "focus_exited").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn gui_input(&self) -> SignalFuture<(Gd<InputEvent>,)> {
-        Signal::from_object_signal(self, "gui_input").to_future::<(Gd<InputEvent>,)>()
+        object_signal(self, "gui_input").to_future::<(Gd<InputEvent>,)>()
     }
     #[inline]
+    #[track_caller]
     fn minimum_size_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "minimum_size_changed").to_future::<()>()
+        object_signal(self, "minimum_size_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn mouse_entered(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "mouse_entered").to_future::<()>()
+        object_signal(self, "mouse_entered").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn mouse_exited(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "mouse_exited").to_future::<()>()
+        object_signal(self, "mouse_exited").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn resized(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "resized").to_future::<()>()
+        object_signal(self, "resized").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn size_flags_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "size_flags_changed").to_future::<()>()
+        object_signal(self, "size_flags_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn theme_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "theme_changed").to_future::<()>()
+        object_signal(self, "theme_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn focus_entered_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "focus_entered").to_fallible_future::<()>()
+        object_signal(self, "focus_entered").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn focus_exited_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "focus_exited").to_fallible_future::<()>()
+        object_signal(self, "focus_exited").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn gui_input_fallible(&self) -> FallibleSignalFuture<(Gd<InputEvent>,)> {
-        Signal::from_object_signal(self, "gui_input").to_fallible_future::<(Gd<InputEvent>,)>()
+        object_signal(self, "gui_input").to_fallible_future::<(Gd<InputEvent>,)>()
     }
     #[inline]
+    #[track_caller]
     fn minimum_size_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "minimum_size_changed").to_fallible_future::<()>()
+        object_signal(self, "minimum_size_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn mouse_entered_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "mouse_entered").to_fallible_future::<()>()
+        object_signal(self, "mouse_entered").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn mouse_exited_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "mouse_exited").to_fallible_future::<()>()
+        object_signal(self, "mouse_exited").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn resized_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "resized").to_fallible_future::<()>()
+        object_signal(self, "resized").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn size_flags_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "size_flags_changed").to_fallible_future::<()>()
+        object_signal(self, "size_flags_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn theme_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "theme_changed").to_fallible_future::<()>()
+        object_signal(self, "theme_changed").to_fallible_future::<()>()
     }
 }
@@ -0,0 +1,188 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::classes::choose::Choose;
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::Vector2;
+use godot::classes::ItemList;
+use godot::task::FallibleSignalFutureError;
+
+pub trait ItemListExt<T>
+where
+    T: Inherits<ItemList>,
+{
+    /// Emitted when any mouse click is issued within the rect of the list but on empty space.
+    /// # Return
+    /// (`at_position`: Vector2, `mouse_button_index`: i64,)
+    fn empty_clicked(&self) -> SignalFuture<(Vector2, i64)>;
+    /// Emitted when specified list item is activated via double-clicking or by pressing Enter.
+    /// # Return
+    /// (`index`: i64,)
+    fn item_activated(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when specified list item has been clicked with any mouse button.
+    /// # Return
+    /// (`index`: i64, `at_position`: Vector2, `mouse_button_index`: i64,)
+    fn item_clicked(&self) -> SignalFuture<(i64, Vector2, i64)>;
+    /// Emitted when specified item has been selected. Only applicable in single selection mode.
+    ///
+    /// `allow_reselect` must be enabled to reselect an item.
+    /// # Return
+    /// (`index`: i64,)
+    fn item_selected(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a multiple selection is altered on a list allowing multiple selection.
+    /// # Return
+    /// (`index`: i64, `selected`: bool,)
+    fn multi_selected(&self) -> SignalFuture<(i64, bool)>;
+    /// `empty_clicked`fallible
+    fn empty_clicked_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)>;
+    /// `item_activated`fallible
+    fn item_activated_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `item_clicked`fallible
+    fn item_clicked_fallible(&self) -> FallibleSignalFuture<(i64, Vector2, i64)>;
+    /// `item_selected`fallible
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `multi_selected`fallible
+    fn multi_selected_fallible(&self) -> FallibleSignalFuture<(i64, bool)>;
+
+    /// Resolves to the index of the item the player activates (double-click or Enter).
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let save_slot = slot_list.choose().await;
+    /// ```
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>>;
+    /// `choose`fallible
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>>;
+    /// Resolves to the indices of all selected items once the player activates an item,
+    /// for lists in `SELECT_MULTI` mode.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let party = roster.choose_multi().await;
+    /// ```
+    fn choose_multi(&self) -> ChooseMulti<SignalFuture<(i64,)>>;
+    /// `choose_multi`fallible
+    fn choose_multi_fallible(&self) -> ChooseMulti<FallibleSignalFuture<(i64,)>>;
+}
+
+impl<T> ItemListExt<T> for Gd<T>
+where
+    T: Inherits<ItemList>,
+{
+    #[inline]
+    #[track_caller]
+    fn empty_clicked(&self) -> SignalFuture<(Vector2, i64)> {
+        object_signal(self, "empty_clicked").to_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_activated(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "item_activated").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_clicked(&self) -> SignalFuture<(i64, Vector2, i64)> {
+        object_signal(self, "item_clicked").to_future::<(i64, Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "item_selected").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn multi_selected(&self) -> SignalFuture<(i64, bool)> {
+        object_signal(self, "multi_selected").to_future::<(i64, bool)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn empty_clicked_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)> {
+        object_signal(self, "empty_clicked").to_fallible_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_activated_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "item_activated").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_clicked_fallible(&self) -> FallibleSignalFuture<(i64, Vector2, i64)> {
+        object_signal(self, "item_clicked").to_fallible_future::<(i64, Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "item_selected").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn multi_selected_fallible(&self) -> FallibleSignalFuture<(i64, bool)> {
+        object_signal(self, "multi_selected").to_fallible_future::<(i64, bool)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>> {
+        Choose(self.item_activated())
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>> {
+        Choose(self.item_activated_fallible())
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_multi(&self) -> ChooseMulti<SignalFuture<(i64,)>> {
+        ChooseMulti {
+            list: self.clone().upcast(),
+            activated: self.item_activated(),
+        }
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_multi_fallible(&self) -> ChooseMulti<FallibleSignalFuture<(i64,)>> {
+        ChooseMulti {
+            list: self.clone().upcast(),
+            activated: self.item_activated_fallible(),
+        }
+    }
+}
+
+/// Future for `ItemListExt::choose_multi` and `ItemListExt::choose_multi_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ChooseMulti<F> {
+    list: Gd<ItemList>,
+    activated: F,
+}
+
+impl<F> ChooseMulti<F> {
+    fn selected_items(&self) -> Vec<i64> {
+        self.list
+            .get_selected_items()
+            .as_slice()
+            .iter()
+            .map(|&index| i64::from(index))
+            .collect()
+    }
+}
+
+impl Future for ChooseMulti<SignalFuture<(i64,)>> {
+    type Output = Vec<i64>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.activated)
+            .poll(cx)
+            .map(|_| self.selected_items())
+    }
+}
+
+impl Future for ChooseMulti<FallibleSignalFuture<(i64,)>> {
+    type Output = Result<Vec<i64>, FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.activated)
+            .poll(cx)
+            .map(|result| result.map(|_| self.selected_items()))
+    }
+}
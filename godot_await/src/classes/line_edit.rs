@@ -0,0 +1,134 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::GString;
+use godot::classes::{Control, LineEdit};
+use godot::task::FallibleSignalFutureError;
+
+pub trait LineEditExt<T>
+where
+    T: Inherits<LineEdit>,
+{
+    /// Emitted when appending text that overflows the `max_length`.
+    /// The appended text is truncated to fit `max_length`, and the part that couldn't fit is passed as the `rejected_substring` argument.
+    /// # Return
+    /// (`rejected_substring`: GString,)
+    fn text_change_rejected(&self) -> SignalFuture<(GString,)>;
+    /// Emitted when the text changes.
+    /// # Return
+    /// (`new_text`: GString,)
+    fn text_changed(&self) -> SignalFuture<(GString,)>;
+    /// Emitted when the user presses the `ui_text_submit` action (by default: Enter or Kp Enter) while the LineEdit has focus.
+    /// # Return
+    /// (`new_text`: GString,)
+    fn text_submitted(&self) -> SignalFuture<(GString,)>;
+    /// Emitted when the LineEdit switches in or out of edit mode.
+    ///
+    /// Only exists since Godot 4.3; on older versions the signal cannot be connected and the future never resolves.
+    /// # Return
+    /// (`toggled_on`: bool,)
+    fn editing_toggled(&self) -> SignalFuture<(bool,)>;
+    /// `text_change_rejected`fallible
+    fn text_change_rejected_fallible(&self) -> FallibleSignalFuture<(GString,)>;
+    /// `text_changed`fallible
+    fn text_changed_fallible(&self) -> FallibleSignalFuture<(GString,)>;
+    /// `text_submitted`fallible
+    fn text_submitted_fallible(&self) -> FallibleSignalFuture<(GString,)>;
+    /// `editing_toggled`fallible
+    fn editing_toggled_fallible(&self) -> FallibleSignalFuture<(bool,)>;
+
+    /// Focuses the LineEdit and resolves to the text the player submits.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let name = name_edit.prompt().await;
+    /// ```
+    fn prompt(&self) -> Prompt<SignalFuture<(GString,)>>;
+    /// `prompt`fallible, resolving to an error if the LineEdit is freed first (e.g. the console is closed).
+    fn prompt_fallible(&self) -> Prompt<FallibleSignalFuture<(GString,)>>;
+}
+
+impl<T> LineEditExt<T> for Gd<T>
+where
+    T: Inherits<LineEdit>,
+{
+    #[inline]
+    #[track_caller]
+    fn text_change_rejected(&self) -> SignalFuture<(GString,)> {
+        object_signal(self, "text_change_rejected").to_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_changed(&self) -> SignalFuture<(GString,)> {
+        object_signal(self, "text_changed").to_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_submitted(&self) -> SignalFuture<(GString,)> {
+        object_signal(self, "text_submitted").to_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn editing_toggled(&self) -> SignalFuture<(bool,)> {
+        object_signal(self, "editing_toggled").to_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_change_rejected_fallible(&self) -> FallibleSignalFuture<(GString,)> {
+        object_signal(self, "text_change_rejected").to_fallible_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_changed_fallible(&self) -> FallibleSignalFuture<(GString,)> {
+        object_signal(self, "text_changed").to_fallible_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_submitted_fallible(&self) -> FallibleSignalFuture<(GString,)> {
+        object_signal(self, "text_submitted").to_fallible_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn editing_toggled_fallible(&self) -> FallibleSignalFuture<(bool,)> {
+        object_signal(self, "editing_toggled").to_fallible_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn prompt(&self) -> Prompt<SignalFuture<(GString,)>> {
+        let submitted = self.text_submitted();
+        self.clone().upcast::<Control>().grab_focus();
+        Prompt(submitted)
+    }
+    #[inline]
+    #[track_caller]
+    fn prompt_fallible(&self) -> Prompt<FallibleSignalFuture<(GString,)>> {
+        let submitted = self.text_submitted_fallible();
+        self.clone().upcast::<Control>().grab_focus();
+        Prompt(submitted)
+    }
+}
+
+/// Future for `LineEditExt::prompt` and `LineEditExt::prompt_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Prompt<F>(F);
+
+impl Future for Prompt<SignalFuture<(GString,)>> {
+    type Output = GString;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx).map(|(text,)| text)
+    }
+}
+
+impl Future for Prompt<FallibleSignalFuture<(GString,)>> {
+    type Output = Result<GString, FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.map(|(text,)| text))
+    }
+}
@@ -0,0 +1,312 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::{Dictionary, Variant, Vector2, Vector3};
+use godot::classes::{NavigationAgent2D, NavigationAgent3D, Node};
+use godot::meta::{FromGodot, ToGodot};
+use godot::task::FallibleSignalFutureError;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for godot::classes::NavigationAgent2D {}
+    impl Sealed for godot::classes::NavigationAgent3D {}
+}
+
+/// Implemented for `NavigationAgent2D` and `NavigationAgent3D`, see [`NavigationAgentExt`].
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait NavigationAgentSignal: Inherits<Node> + sealed::Sealed {
+    /// `Vector2` or `Vector3`.
+    type Vector: FromGodot + ToGodot + PartialEq + Copy + Unpin + fmt::Debug;
+
+    #[doc(hidden)]
+    fn target_position(agent: &Gd<Self>) -> Self::Vector;
+    #[doc(hidden)]
+    fn set_target_position(agent: &mut Gd<Self>, target: Self::Vector);
+    #[doc(hidden)]
+    fn is_target_reached(agent: &Gd<Self>) -> bool;
+}
+
+impl NavigationAgentSignal for NavigationAgent2D {
+    type Vector = Vector2;
+
+    fn target_position(agent: &Gd<Self>) -> Self::Vector {
+        agent.get_target_position()
+    }
+    fn set_target_position(agent: &mut Gd<Self>, target: Self::Vector) {
+        agent.set_target_position(target);
+    }
+    fn is_target_reached(agent: &Gd<Self>) -> bool {
+        agent.is_target_reached()
+    }
+}
+impl NavigationAgentSignal for NavigationAgent3D {
+    type Vector = Vector3;
+
+    fn target_position(agent: &Gd<Self>) -> Self::Vector {
+        agent.get_target_position()
+    }
+    fn set_target_position(agent: &mut Gd<Self>, target: Self::Vector) {
+        agent.set_target_position(target);
+    }
+    fn is_target_reached(agent: &Gd<Self>) -> bool {
+        agent.is_target_reached()
+    }
+}
+
+pub trait NavigationAgentExt<T>
+where
+    T: NavigationAgentSignal,
+{
+    /// Signals that the agent reached a navigation link. Emitted when the agent moves within `path_desired_distance` of the next position of the path when that position is a navigation link.
+    ///
+    /// The details dictionary may contain the keys `position`, `type`, `rid`, `owner`, `link_entry_position` and `link_exit_position`,
+    /// depending on the value of `path_metadata_flags`.
+    /// # Return
+    /// (`details`: Dictionary,)
+    fn link_reached(&self) -> SignalFuture<(Dictionary,)>;
+    /// Signals that the agent's navigation has finished. If the target is reachable, navigation ends when the target is reached.
+    /// If the target is unreachable, navigation ends when the last waypoint of the path is reached.
+    /// This signal is emitted only once per loaded path.
+    ///
+    /// This signal will be emitted just after `target_reached` when the target is reachable.
+    fn navigation_finished(&self) -> SignalFuture<()>;
+    /// Emitted when the agent had to update the loaded path:
+    /// - because path was previously empty.
+    /// - because navigation map has changed.
+    /// - because agent pushed further away from the current path segment than the `path_max_distance`.
+    fn path_changed(&self) -> SignalFuture<()>;
+    /// Signals that the agent reached the target, i.e. the agent moved within `target_desired_distance` of the `target_position`.
+    /// This signal is emitted only once per loaded path.
+    ///
+    /// This signal will be emitted just before `navigation_finished` when the target is reachable.
+    fn target_reached(&self) -> SignalFuture<()>;
+    /// Signals that the agent reached a waypoint. Emitted when the agent moves within `path_desired_distance` of the next position of the path.
+    ///
+    /// The details dictionary may contain the keys `position`, `type`, `rid` and `owner`, depending on the value of `path_metadata_flags`.
+    /// # Return
+    /// (`details`: Dictionary,)
+    fn waypoint_reached(&self) -> SignalFuture<(Dictionary,)>;
+    /// Emitted when the avoidance velocity was computed, with the safe velocity (`Vector2` or `Vector3`).
+    /// Only emitted with `avoidance_enabled`, after `set_velocity()`.
+    /// # Return
+    /// (`safe_velocity`: T::Vector,)
+    fn velocity_computed(&self) -> VelocityComputed<T::Vector, SignalFuture<(Variant,)>>;
+    /// `link_reached`fallible
+    fn link_reached_fallible(&self) -> FallibleSignalFuture<(Dictionary,)>;
+    /// `navigation_finished`fallible
+    fn navigation_finished_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `path_changed`fallible
+    fn path_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `target_reached`fallible
+    fn target_reached_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `waypoint_reached`fallible
+    fn waypoint_reached_fallible(&self) -> FallibleSignalFuture<(Dictionary,)>;
+    /// `velocity_computed`fallible
+    fn velocity_computed_fallible(
+        &self,
+    ) -> VelocityComputed<T::Vector, FallibleSignalFuture<(Variant,)>>;
+
+    /// Sets `target_position` and resolves once the navigation finished.
+    ///
+    /// The agent still has to be moved by your code, following `get_next_path_position()` every physics frame;
+    /// navigation only finishes while it does.
+    /// Resolves to `Unreachable` if the navigation ends short of the target, and to `Interrupted` as soon as the target
+    /// is changed (e.g. by another `move_to`, noticed when the agent updates its path) or the agent is freed.
+    ///
+    /// If the agent already reached `target`, this resolves to `Reached` right away, since no navigation is left to
+    /// finish. Any other target, including the current one, is set again, which requests a new path.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// match agent.move_to(Vector3::new(4.0, 0.0, 2.0)).await {
+    ///     NavigationResult::Reached => npc.play_idle(),
+    ///     NavigationResult::Unreachable => npc.shrug(),
+    ///     NavigationResult::Interrupted => {}
+    /// }
+    /// ```
+    fn move_to(&self, target: T::Vector) -> MoveTo<T>;
+}
+
+impl<T> NavigationAgentExt<T> for Gd<T>
+where
+    T: NavigationAgentSignal,
+{
+    #[inline]
+    #[track_caller]
+    fn link_reached(&self) -> SignalFuture<(Dictionary,)> {
+        object_signal(self, "link_reached").to_future::<(Dictionary,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn navigation_finished(&self) -> SignalFuture<()> {
+        object_signal(self, "navigation_finished").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn path_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "path_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn target_reached(&self) -> SignalFuture<()> {
+        object_signal(self, "target_reached").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn waypoint_reached(&self) -> SignalFuture<(Dictionary,)> {
+        object_signal(self, "waypoint_reached").to_future::<(Dictionary,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn velocity_computed(&self) -> VelocityComputed<T::Vector, SignalFuture<(Variant,)>> {
+        VelocityComputed {
+            inner: object_signal(self, "velocity_computed").to_future::<(Variant,)>(),
+            _vector: PhantomData,
+        }
+    }
+    #[inline]
+    #[track_caller]
+    fn link_reached_fallible(&self) -> FallibleSignalFuture<(Dictionary,)> {
+        object_signal(self, "link_reached").to_fallible_future::<(Dictionary,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn navigation_finished_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "navigation_finished").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn path_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "path_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn target_reached_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "target_reached").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn waypoint_reached_fallible(&self) -> FallibleSignalFuture<(Dictionary,)> {
+        object_signal(self, "waypoint_reached").to_fallible_future::<(Dictionary,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn velocity_computed_fallible(
+        &self,
+    ) -> VelocityComputed<T::Vector, FallibleSignalFuture<(Variant,)>> {
+        VelocityComputed {
+            inner: object_signal(self, "velocity_computed").to_fallible_future::<(Variant,)>(),
+            _vector: PhantomData,
+        }
+    }
+    #[inline]
+    #[track_caller]
+    fn move_to(&self, target: T::Vector) -> MoveTo<T> {
+        let finished = self.navigation_finished_fallible();
+        let path_changed = self.path_changed_fallible();
+        let mut agent = self.clone();
+        // Setting the target again would restart the navigation, which only finishes once the agent moves.
+        let reached = agent.is_instance_valid()
+            && T::target_position(&agent) == target
+            && T::is_target_reached(&agent);
+        if !reached {
+            T::set_target_position(&mut agent, target);
+        }
+        MoveTo {
+            agent,
+            target,
+            reached,
+            finished,
+            path_changed,
+        }
+    }
+}
+
+/// Future for `NavigationAgentExt::velocity_computed` and `NavigationAgentExt::velocity_computed_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct VelocityComputed<V, F> {
+    inner: F,
+    _vector: PhantomData<fn() -> V>,
+}
+
+impl<V: FromGodot> Future for VelocityComputed<V, SignalFuture<(Variant,)>> {
+    type Output = (V,);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner)
+            .poll(cx)
+            .map(|(velocity,)| (velocity.to::<V>(),))
+    }
+}
+
+impl<V: FromGodot> Future for VelocityComputed<V, FallibleSignalFuture<(Variant,)>> {
+    type Output = Result<(V,), FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.inner)
+            .poll(cx)
+            .map(|result| result.map(|(velocity,)| (velocity.to::<V>(),)))
+    }
+}
+
+/// How a `NavigationAgentExt::move_to` ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationResult {
+    /// The agent reached the target.
+    Reached,
+    /// The navigation finished short of the target, as no path leads there.
+    Unreachable,
+    /// The target was changed before the navigation finished, or the agent was freed.
+    Interrupted,
+}
+
+/// Future for `NavigationAgentExt::move_to`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MoveTo<T: NavigationAgentSignal> {
+    agent: Gd<T>,
+    target: T::Vector,
+    /// Whether the agent had already reached `target` when `move_to` was called.
+    reached: bool,
+    finished: FallibleSignalFuture<()>,
+    /// Wakes the future when the target may have changed, since a new target is only followed by a path update.
+    path_changed: FallibleSignalFuture<()>,
+}
+
+impl<T: NavigationAgentSignal> Future for MoveTo<T> {
+    type Output = NavigationResult;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        if this.reached {
+            return Poll::Ready(NavigationResult::Reached);
+        }
+        loop {
+            if !this.agent.is_instance_valid() || T::target_position(&this.agent) != this.target {
+                return Poll::Ready(NavigationResult::Interrupted);
+            }
+
+            match Pin::new(&mut this.finished).poll(cx) {
+                Poll::Ready(Err(_)) => return Poll::Ready(NavigationResult::Interrupted),
+                Poll::Ready(Ok(())) if T::is_target_reached(&this.agent) => {
+                    return Poll::Ready(NavigationResult::Reached);
+                }
+                Poll::Ready(Ok(())) => return Poll::Ready(NavigationResult::Unreachable),
+                Poll::Pending => {}
+            }
+
+            match Pin::new(&mut this.path_changed).poll(cx) {
+                Poll::Ready(Err(_)) => return Poll::Ready(NavigationResult::Interrupted),
+                // Check the target again, and wait for the next update.
+                Poll::Ready(Ok(())) => this.path_changed = this.agent.path_changed_fallible(),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
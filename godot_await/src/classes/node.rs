@@ -1,5 +1,10 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::classes::tween::TweenBuilder;
+use crate::utils::object_signal;
+use crate::wait::{ExNodeWaitBuilder, NodeWait};
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::NodePath;
 use godot::classes::{Node, Node3D};
+use godot::meta::{AsArg, ToGodot};
 
 pub trait NodeExt<T>
 where
@@ -53,6 +58,45 @@ where
     fn replacing_by_fallible(&self) -> FallibleSignalFuture<()>;
     fn tree_entered_fallible(&self) -> FallibleSignalFuture<()>;
     fn tree_exited_fallible(&self) -> FallibleSignalFuture<()>;
+
+    /// Creates a Tween bound to this node, tweening `property` to `final_val` over `duration_sec` seconds.
+    ///
+    /// The returned builder can be configured further and awaited;
+    /// it resolves to `Err(TweenKilled)` instead of hanging if the Tween is killed.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// node.tween_property_async("position", Vector2::new(100.0, 0.0), 1.0).await?;
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if create `Tween` failed
+    fn tween_property_async(
+        &self,
+        property: impl AsArg<NodePath>,
+        final_val: impl ToGodot,
+        duration_sec: f64,
+    ) -> TweenBuilder;
+
+    /// Waits `time_sec` seconds, counting only the frames in which this node can process.
+    ///
+    /// Unlike [`wait()`](crate::wait::wait), this honours the node's effective `process_mode`:
+    /// it stops while the node is paused or disabled, and keeps running in `PROCESS_MODE_WHEN_PAUSED`/`ALWAYS` nodes.
+    /// Resolves to `Err(LeftTree)` if the node leaves the tree (or is freed) first.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// pause_menu.wait(0.5).await?;
+    /// ```
+    fn wait(&self, time_sec: f64) -> NodeWait;
+
+    /// Like [`wait`](Self::wait), configurable with the returned builder.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// pause_menu.wait_ex(0.5).ignore_time_scale(true).done().await?;
+    /// ```
+    fn wait_ex(&self, time_sec: f64) -> ExNodeWaitBuilder;
 }
 
 impl<T> NodeExt<T> for Gd<T>
@@ -60,91 +104,136 @@ where
     T: Inherits<Node>,
 {
     #[inline]
+    #[track_caller]
     fn child_entered_tree(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "child_entered_tree").to_future::<(Gd<Node>,)>()
+        object_signal(self, "child_entered_tree").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn child_exiting_tree(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "child_exiting_tree").to_future::<(Gd<Node>,)>()
+        object_signal(self, "child_exiting_tree").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn child_order_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "child_order_changed").to_future::<()>()
+        object_signal(self, "child_order_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn editor_description_changed(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "editor_description_changed").to_future::<(Gd<Node>,)>()
+        object_signal(self, "editor_description_changed").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn editor_state_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "editor_state_changed").to_future::<()>()
+        object_signal(self, "editor_state_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn ready(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "ready").to_future::<()>()
+        object_signal(self, "ready").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn renamed(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "renamed").to_future::<(Gd<Node>,)>()
+        object_signal(self, "renamed").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn replacing_by(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "replacing_by").to_future::<()>()
+        object_signal(self, "replacing_by").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_entered(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "tree_entered").to_future::<()>()
+        object_signal(self, "tree_entered").to_future::<()>()
     }
 
     #[inline]
+    #[track_caller]
     fn tree_exited(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "tree_exited").to_future::<()>()
+        object_signal(self, "tree_exited").to_future::<()>()
     }
 
     #[inline]
+    #[track_caller]
     fn tree_exiting(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "tree_exiting").to_future::<()>()
+        object_signal(self, "tree_exiting").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn child_entered_tree_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "child_entered_tree").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "child_entered_tree").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn child_exiting_tree_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "child_exiting_tree").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "child_exiting_tree").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn child_order_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "child_order_changed").to_fallible_future::<()>()
+        object_signal(self, "child_order_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn editor_description_changed_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "editor_description_changed")
-            .to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "editor_description_changed").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn editor_state_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "editor_state_changed").to_fallible_future::<()>()
+        object_signal(self, "editor_state_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn ready_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "ready").to_fallible_future::<()>()
+        object_signal(self, "ready").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn renamed_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "renamed").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "renamed").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn replacing_by_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "replacing_by").to_fallible_future::<()>()
+        object_signal(self, "replacing_by").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_entered_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "tree_entered").to_fallible_future::<()>()
+        object_signal(self, "tree_entered").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_exited_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "tree_exited").to_fallible_future::<()>()
+        object_signal(self, "tree_exited").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tween_property_async(
+        &self,
+        property: impl AsArg<NodePath>,
+        final_val: impl ToGodot,
+        duration_sec: f64,
+    ) -> TweenBuilder {
+        TweenBuilder::new(
+            self.clone().upcast(),
+            property,
+            &final_val.to_variant(),
+            duration_sec,
+        )
+    }
+
+    #[inline]
+    #[track_caller]
+    fn wait(&self, time_sec: f64) -> NodeWait {
+        self.wait_ex(time_sec).done()
+    }
+    #[inline]
+    fn wait_ex(&self, time_sec: f64) -> ExNodeWaitBuilder {
+        ExNodeWaitBuilder::new(self.clone().upcast(), time_sec)
     }
 }
 
@@ -162,11 +251,13 @@ where
     T: Inherits<Node3D>,
 {
     #[inline]
+    #[track_caller]
     fn visibility_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "visibility_changed").to_future::<()>()
+        object_signal(self, "visibility_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn visibility_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "visibility_changed").to_fallible_future::<()>()
+        object_signal(self, "visibility_changed").to_fallible_future::<()>()
     }
 }
@@ -0,0 +1,71 @@
+use crate::classes::choose::Choose;
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::classes::OptionButton;
+
+pub trait OptionButtonExt<T>
+where
+    T: Inherits<OptionButton>,
+{
+    /// Emitted when the user navigates to an item using the `ui_up` or `ui_down` input actions.
+    /// The index of the item focused is passed as argument.
+    /// # Return
+    /// (`index`: i64,)
+    fn item_focused(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when the current item has been changed by the user. The index of the item selected is passed as argument.
+    ///
+    /// `allow_reselect` must be enabled to reselect an item.
+    /// # Return
+    /// (`index`: i64,)
+    fn item_selected(&self) -> SignalFuture<(i64,)>;
+    /// `item_focused`fallible
+    fn item_focused_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `item_selected`fallible
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+
+    /// Resolves to the index of the item the player selects.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let difficulty = difficulty_button.choose().await;
+    /// ```
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>>;
+    /// `choose`fallible
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>>;
+}
+
+impl<T> OptionButtonExt<T> for Gd<T>
+where
+    T: Inherits<OptionButton>,
+{
+    #[inline]
+    #[track_caller]
+    fn item_focused(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "item_focused").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "item_selected").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_focused_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "item_focused").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "item_selected").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>> {
+        Choose(self.item_selected())
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>> {
+        Choose(self.item_selected_fallible())
+    }
+}
@@ -0,0 +1,198 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::Rect2i;
+use godot::classes::{MenuButton, PopupMenu, Window};
+
+pub trait PopupMenuExt<T>
+where
+    T: Inherits<PopupMenu>,
+{
+    /// Emitted when the user navigated to an item of some `id` using the `ProjectSettings.input/ui_up` or `ProjectSettings.input/ui_down` input action.
+    /// # Return
+    /// (`id`: i64,)
+    fn id_focused(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when an item of some `id` is pressed or its accelerator is activated.
+    ///
+    /// Note: If `id` is negative (either explicitly or due to overflow), this will return the corresponding index instead.
+    /// # Return
+    /// (`id`: i64,)
+    fn id_pressed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when an item of some `index` is pressed or its accelerator is activated.
+    /// # Return
+    /// (`index`: i64,)
+    fn index_pressed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when any item is added, modified or removed.
+    fn menu_changed(&self) -> SignalFuture<()>;
+    /// Emitted when the popup is hidden.
+    fn popup_hide(&self) -> SignalFuture<()>;
+    /// `id_focused`fallible
+    fn id_focused_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `id_pressed`fallible
+    fn id_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `index_pressed`fallible
+    fn index_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `menu_changed`fallible
+    fn menu_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `popup_hide`fallible
+    fn popup_hide_fallible(&self) -> FallibleSignalFuture<()>;
+
+    /// Shows the menu in `rect` (in screen coordinates, see `Window.popup()`),
+    /// and resolves to the id of the pressed item, or `None` if the menu is dismissed without a choice.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let position = Vector2i::new(120, 80);
+    /// match context_menu.popup_and_choose(Rect2i::new(position, Vector2i::ZERO)).await {
+    ///     Some(DELETE_ID) => delete_selected(),
+    ///     _ => {}
+    /// }
+    /// ```
+    fn popup_and_choose(&self, rect: Rect2i) -> PopupChoice;
+    /// Like `popup_and_choose`, for a menu shown elsewhere (e.g. by a `MenuButton`).
+    fn choose(&self) -> PopupChoice;
+}
+
+impl<T> PopupMenuExt<T> for Gd<T>
+where
+    T: Inherits<PopupMenu>,
+{
+    #[inline]
+    #[track_caller]
+    fn id_focused(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "id_focused").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn id_pressed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "id_pressed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn index_pressed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "index_pressed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn menu_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "menu_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn popup_hide(&self) -> SignalFuture<()> {
+        object_signal(self, "popup_hide").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn id_focused_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "id_focused").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn id_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "id_pressed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn index_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "index_pressed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn menu_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "menu_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn popup_hide_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "popup_hide").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn popup_and_choose(&self, rect: Rect2i) -> PopupChoice {
+        let choice = self.choose();
+        self.clone().upcast::<Window>().popup_ex().rect(rect).done();
+        choice
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> PopupChoice {
+        PopupChoice {
+            pressed: self.id_pressed_fallible(),
+            hidden: self.popup_hide_fallible(),
+        }
+    }
+}
+
+pub trait MenuButtonExt<T>
+where
+    T: Inherits<MenuButton>,
+{
+    /// Emitted when the `PopupMenu` of this MenuButton is about to show.
+    fn about_to_popup(&self) -> SignalFuture<()>;
+    /// `about_to_popup`fallible
+    fn about_to_popup_fallible(&self) -> FallibleSignalFuture<()>;
+
+    /// Resolves to the id of the item pressed in the popup of this MenuButton,
+    /// or `None` if the popup is dismissed without a choice. The button opens the popup as usual.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// if let Some(id) = file_menu.choose().await {
+    ///     run_command(id);
+    /// }
+    /// ```
+    fn choose(&self) -> PopupChoice;
+}
+
+impl<T> MenuButtonExt<T> for Gd<T>
+where
+    T: Inherits<MenuButton>,
+{
+    #[inline]
+    #[track_caller]
+    fn about_to_popup(&self) -> SignalFuture<()> {
+        object_signal(self, "about_to_popup").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn about_to_popup_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "about_to_popup").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> PopupChoice {
+        let popup = self
+            .clone()
+            .upcast::<MenuButton>()
+            .get_popup()
+            .expect("ERR(godot_await):get MenuButton popup failed");
+        popup.choose()
+    }
+}
+
+/// Future for `PopupMenuExt::popup_and_choose`, `PopupMenuExt::choose` and `MenuButtonExt::choose`.
+///
+/// Races `id_pressed` against `popup_hide`. Pressing an item hides the menu (`popup_hide`) before emitting `id_pressed`,
+/// but both signals are emitted before the task is polled again, and `id_pressed` is polled first,
+/// so a pressed item still wins over the dismissal. Resolves to `None` if the menu is freed.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct PopupChoice {
+    pressed: FallibleSignalFuture<(i64,)>,
+    hidden: FallibleSignalFuture<()>,
+}
+
+impl Future for PopupChoice {
+    type Output = Option<i64>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // `popup_hide` is emitted first when an item is pressed, so `id_pressed` has to be checked first.
+        if let Poll::Ready(pressed) = Pin::new(&mut self.pressed).poll(cx) {
+            return Poll::Ready(pressed.ok().map(|(id,)| id));
+        }
+        Pin::new(&mut self.hidden).poll(cx).map(|_| None)
+    }
+}
@@ -0,0 +1,199 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::classes::{Range, Slider};
+use godot::task::FallibleSignalFutureError;
+
+pub trait RangeExt<T>
+where
+    T: Inherits<Range>,
+{
+    /// Emitted when `min_value`, `max_value`, `page`, or `step` change.
+    fn changed(&self) -> SignalFuture<()>;
+    /// Emitted when `value` changes.
+    /// When used on a Slider, this is called continuously while dragging (potentially every frame).
+    /// If you are performing an expensive operation in a function connected to `value_changed`, consider using a debouncing Timer to call the function less often.
+    ///
+    /// Note: Unlike signals such as `LineEdit.text_changed`, `value_changed` is also emitted when `value` is set directly via code.
+    /// # Return
+    /// (`value`: f64,)
+    fn value_changed(&self) -> SignalFuture<(f64,)>;
+    /// `changed`fallible
+    fn changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `value_changed`fallible
+    fn value_changed_fallible(&self) -> FallibleSignalFuture<(f64,)>;
+
+    /// Resolves to the value once it reaches `threshold`, e.g. a loading bar filling up or a cooldown meter running out.
+    ///
+    /// The direction is taken from the value when this is called: below `threshold` it waits for `value >= threshold`,
+    /// above it for `value <= threshold`. Resolves right away if the value is already there.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// loading_bar.wait_value_reaches(100.0).await;
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if the Range is freed before
+    fn wait_value_reaches(&self, threshold: f64) -> ValueReaches;
+    /// `wait_value_reaches`fallible
+    fn wait_value_reaches_fallible(&self, threshold: f64) -> ValueReachesFallible;
+}
+
+impl<T> RangeExt<T> for Gd<T>
+where
+    T: Inherits<Range>,
+{
+    #[inline]
+    #[track_caller]
+    fn changed(&self) -> SignalFuture<()> {
+        object_signal(self, "changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn value_changed(&self) -> SignalFuture<(f64,)> {
+        object_signal(self, "value_changed").to_future::<(f64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn value_changed_fallible(&self) -> FallibleSignalFuture<(f64,)> {
+        object_signal(self, "value_changed").to_fallible_future::<(f64,)>()
+    }
+    #[inline]
+    fn wait_value_reaches(&self, threshold: f64) -> ValueReaches {
+        ValueReaches::new(self.clone().upcast(), threshold)
+    }
+    #[inline]
+    fn wait_value_reaches_fallible(&self, threshold: f64) -> ValueReachesFallible {
+        ValueReachesFallible(ValueReaches::new(self.clone().upcast(), threshold))
+    }
+}
+
+pub trait SliderExt<T>
+where
+    T: Inherits<Slider>,
+{
+    /// Emitted when the grabber stops being dragged. If `value_changed` is true, `Range.value` is different from the value when you started the dragging.
+    /// # Return
+    /// (`value_changed`: bool,)
+    fn drag_ended(&self) -> SignalFuture<(bool,)>;
+    /// Emitted when the grabber starts being dragged. This is emitted before the corresponding `Range.value_changed` signal.
+    fn drag_started(&self) -> SignalFuture<()>;
+    /// `drag_ended`fallible
+    fn drag_ended_fallible(&self) -> FallibleSignalFuture<(bool,)>;
+    /// `drag_started`fallible
+    fn drag_started_fallible(&self) -> FallibleSignalFuture<()>;
+}
+
+impl<T> SliderExt<T> for Gd<T>
+where
+    T: Inherits<Slider>,
+{
+    #[inline]
+    #[track_caller]
+    fn drag_ended(&self) -> SignalFuture<(bool,)> {
+        object_signal(self, "drag_ended").to_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn drag_started(&self) -> SignalFuture<()> {
+        object_signal(self, "drag_started").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn drag_ended_fallible(&self) -> FallibleSignalFuture<(bool,)> {
+        object_signal(self, "drag_ended").to_fallible_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn drag_started_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "drag_started").to_fallible_future::<()>()
+    }
+}
+
+/// Future for `RangeExt::wait_value_reaches`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ValueReaches {
+    range: Gd<Range>,
+    threshold: f64,
+    /// Whether the value started below `threshold`.
+    rising: bool,
+    changed: Option<FallibleSignalFuture<(f64,)>>,
+}
+
+impl ValueReaches {
+    fn new(range: Gd<Range>, threshold: f64) -> Self {
+        // A freed Range resolves to an error at the first poll anyway.
+        let rising = range.is_instance_valid() && range.get_value() < threshold;
+        ValueReaches {
+            range,
+            threshold,
+            rising,
+            changed: None,
+        }
+    }
+
+    fn poll_reaches(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<f64, FallibleSignalFutureError>> {
+        loop {
+            if !self.range.is_instance_valid() {
+                return Poll::Ready(Err(FallibleSignalFutureError));
+            }
+
+            let value = self.range.get_value();
+            let reached = if self.rising {
+                value >= self.threshold
+            } else {
+                value <= self.threshold
+            };
+            if reached {
+                return Poll::Ready(Ok(value));
+            }
+
+            // The value is read again after every emission, so emissions between two polls cannot be missed.
+            let range = &self.range;
+            let changed = self.changed.get_or_insert_with(|| {
+                object_signal(range, "value_changed").to_fallible_future::<(f64,)>()
+            });
+            match Pin::new(changed).poll(cx) {
+                Poll::Ready(result) => {
+                    self.changed = None;
+                    result?;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Future for ValueReaches {
+    type Output = f64;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.poll_reaches(cx).map(|result| {
+            result.expect("ERR(godot_await):Range was freed before reaching the value")
+        })
+    }
+}
+
+/// Future for `RangeExt::wait_value_reaches_fallible`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ValueReachesFallible(ValueReaches);
+
+impl Future for ValueReachesFallible {
+    type Output = Result<f64, FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_reaches(cx)
+    }
+}
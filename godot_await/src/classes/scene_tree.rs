@@ -1,4 +1,5 @@
-use crate::{Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{Gd, Inherits, SignalFuture};
 use godot::{
     classes::{Node, SceneTree},
     task::FallibleSignalFuture,
@@ -48,70 +49,85 @@ where
     T: Inherits<SceneTree>,
 {
     #[inline]
+    #[track_caller]
     fn node_added(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_added").to_future::<(Gd<Node>,)>()
+        object_signal(self, "node_added").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_configuration_warning_changed(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_configuration_warning_changed")
-            .to_future::<(Gd<Node>,)>()
+        object_signal(self, "node_configuration_warning_changed").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_removed(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_removed").to_future::<(Gd<Node>,)>()
+        object_signal(self, "node_removed").to_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_renamed(&self) -> SignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_renamed").to_future::<(Gd<Node>,)>()
+        object_signal(self, "node_renamed").to_future::<(Gd<Node>,)>()
     }
 
     #[inline]
+    #[track_caller]
     fn physics_frame(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "physics_frame").to_future::<()>()
+        object_signal(self, "physics_frame").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn process_frame(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "process_frame").to_future::<()>()
+        object_signal(self, "process_frame").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "tree_changed").to_future::<()>()
+        object_signal(self, "tree_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_process_mode_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "tree_process_mode_changed").to_future::<()>()
+        object_signal(self, "tree_process_mode_changed").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn node_added_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_added").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "node_added").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_configuration_warning_changed_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_configuration_warning_changed")
+        object_signal(self, "node_configuration_warning_changed")
             .to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_removed_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_removed").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "node_removed").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn node_renamed_fallible(&self) -> FallibleSignalFuture<(Gd<Node>,)> {
-        Signal::from_object_signal(self, "node_renamed").to_fallible_future::<(Gd<Node>,)>()
+        object_signal(self, "node_renamed").to_fallible_future::<(Gd<Node>,)>()
     }
     #[inline]
+    #[track_caller]
     fn physics_frame_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "physics_frame").to_fallible_future::<()>()
+        object_signal(self, "physics_frame").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn process_frame_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "process_frame").to_fallible_future::<()>()
+        object_signal(self, "process_frame").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "tree_changed").to_fallible_future::<()>()
+        object_signal(self, "tree_changed").to_fallible_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn tree_process_mode_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "tree_process_mode_changed").to_fallible_future::<()>()
+        object_signal(self, "tree_process_mode_changed").to_fallible_future::<()>()
     }
 }
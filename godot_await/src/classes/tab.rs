@@ -0,0 +1,298 @@
+use crate::classes::choose::Choose;
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::classes::{TabBar, TabContainer};
+
+pub trait TabBarExt<T>
+where
+    T: Inherits<TabBar>,
+{
+    /// Emitted when the active tab is rearranged via mouse drag. See `drag_to_rearrange_enabled`.
+    /// # Return
+    /// (`idx_to`: i64,)
+    fn active_tab_rearranged(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab's right button is pressed. See `set_tab_button_icon()`.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_button_pressed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when switching to another tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_changed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is clicked, even if it is the current tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_clicked(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab's close button is pressed.
+    ///
+    /// Note: Tabs are not removed automatically once the close button is pressed, this behavior needs to be programmed manually.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_close_pressed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is hovered by the mouse.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_hovered(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is right-clicked. `select_with_rmb` must be enabled.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_rmb_clicked(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is selected via click, directional input, or script, even if it is the current tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_selected(&self) -> SignalFuture<(i64,)>;
+    /// `active_tab_rearranged`fallible
+    fn active_tab_rearranged_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_button_pressed`fallible
+    fn tab_button_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_changed`fallible
+    fn tab_changed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_clicked`fallible
+    fn tab_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_close_pressed`fallible
+    fn tab_close_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_hovered`fallible
+    fn tab_hovered_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_rmb_clicked`fallible
+    fn tab_rmb_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_selected`fallible
+    fn tab_selected_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+
+    /// Resolves to the index of the tab the player switches to.
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>>;
+    /// `choose`fallible
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>>;
+}
+
+impl<T> TabBarExt<T> for Gd<T>
+where
+    T: Inherits<TabBar>,
+{
+    #[inline]
+    #[track_caller]
+    fn active_tab_rearranged(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "active_tab_rearranged").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_button_pressed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_button_pressed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_changed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_changed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_clicked(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_clicked").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_close_pressed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_close_pressed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_hovered(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_hovered").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_rmb_clicked(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_rmb_clicked").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_selected(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_selected").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn active_tab_rearranged_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "active_tab_rearranged").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_button_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_button_pressed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_changed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_changed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_clicked").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_close_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_close_pressed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_hovered_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_hovered").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_rmb_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_rmb_clicked").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_selected_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_selected").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>> {
+        Choose(self.tab_changed())
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>> {
+        Choose(self.tab_changed_fallible())
+    }
+}
+
+pub trait TabContainerExt<T>
+where
+    T: Inherits<TabContainer>,
+{
+    /// Emitted when the active tab is rearranged via mouse drag. See `drag_to_rearrange_enabled`.
+    /// # Return
+    /// (`idx_to`: i64,)
+    fn active_tab_rearranged(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when the `TabContainer`'s `Popup` button is clicked. See `set_popup()` for details.
+    fn pre_popup_pressed(&self) -> SignalFuture<()>;
+    /// Emitted when the user clicks on the button icon on this tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_button_pressed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when switching to another tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_changed(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is clicked, even if it is the current tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_clicked(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is hovered by the mouse.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_hovered(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a tab is selected via click, directional input, or script, even if it is the current tab.
+    /// # Return
+    /// (`tab`: i64,)
+    fn tab_selected(&self) -> SignalFuture<(i64,)>;
+    /// `active_tab_rearranged`fallible
+    fn active_tab_rearranged_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `pre_popup_pressed`fallible
+    fn pre_popup_pressed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `tab_button_pressed`fallible
+    fn tab_button_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_changed`fallible
+    fn tab_changed_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_clicked`fallible
+    fn tab_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_hovered`fallible
+    fn tab_hovered_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `tab_selected`fallible
+    fn tab_selected_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+
+    /// Resolves to the index of the tab the player switches to.
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>>;
+    /// `choose`fallible
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>>;
+}
+
+impl<T> TabContainerExt<T> for Gd<T>
+where
+    T: Inherits<TabContainer>,
+{
+    #[inline]
+    #[track_caller]
+    fn active_tab_rearranged(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "active_tab_rearranged").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn pre_popup_pressed(&self) -> SignalFuture<()> {
+        object_signal(self, "pre_popup_pressed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_button_pressed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_button_pressed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_changed(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_changed").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_clicked(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_clicked").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_hovered(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_hovered").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_selected(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "tab_selected").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn active_tab_rearranged_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "active_tab_rearranged").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn pre_popup_pressed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "pre_popup_pressed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_button_pressed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_button_pressed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_changed_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_changed").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_clicked").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_hovered_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_hovered").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn tab_selected_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "tab_selected").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn choose(&self) -> Choose<SignalFuture<(i64,)>> {
+        Choose(self.tab_changed())
+    }
+    #[inline]
+    #[track_caller]
+    fn choose_fallible(&self) -> Choose<FallibleSignalFuture<(i64,)>> {
+        Choose(self.tab_changed_fallible())
+    }
+}
@@ -0,0 +1,196 @@
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::GString;
+use godot::classes::{CodeEdit, TextEdit};
+
+pub trait TextEditExt<T>
+where
+    T: Inherits<TextEdit>,
+{
+    /// Emitted when the caret changes position.
+    fn caret_changed(&self) -> SignalFuture<()>;
+    /// Emitted when a gutter is added.
+    fn gutter_added(&self) -> SignalFuture<()>;
+    /// Emitted when a gutter is clicked.
+    /// # Return
+    /// (`line`: i64, `gutter`: i64,)
+    fn gutter_clicked(&self) -> SignalFuture<(i64, i64)>;
+    /// Emitted when a gutter is removed.
+    fn gutter_removed(&self) -> SignalFuture<()>;
+    /// Emitted immediately when the text changes.
+    ///
+    /// When text is added `from_line` will be less than `to_line`. On a remove `to_line` will be less than `from_line`.
+    /// # Return
+    /// (`from_line`: i64, `to_line`: i64,)
+    fn lines_edited_from(&self) -> SignalFuture<(i64, i64)>;
+    /// Emitted when the text changes.
+    fn text_changed(&self) -> SignalFuture<()>;
+    /// Emitted when `clear()` is called or `text` is set.
+    fn text_set(&self) -> SignalFuture<()>;
+    /// `caret_changed`fallible
+    fn caret_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `gutter_added`fallible
+    fn gutter_added_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `gutter_clicked`fallible
+    fn gutter_clicked_fallible(&self) -> FallibleSignalFuture<(i64, i64)>;
+    /// `gutter_removed`fallible
+    fn gutter_removed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `lines_edited_from`fallible
+    fn lines_edited_from_fallible(&self) -> FallibleSignalFuture<(i64, i64)>;
+    /// `text_changed`fallible
+    fn text_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `text_set`fallible
+    fn text_set_fallible(&self) -> FallibleSignalFuture<()>;
+}
+
+impl<T> TextEditExt<T> for Gd<T>
+where
+    T: Inherits<TextEdit>,
+{
+    #[inline]
+    #[track_caller]
+    fn caret_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "caret_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_added(&self) -> SignalFuture<()> {
+        object_signal(self, "gutter_added").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_clicked(&self) -> SignalFuture<(i64, i64)> {
+        object_signal(self, "gutter_clicked").to_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_removed(&self) -> SignalFuture<()> {
+        object_signal(self, "gutter_removed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn lines_edited_from(&self) -> SignalFuture<(i64, i64)> {
+        object_signal(self, "lines_edited_from").to_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "text_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_set(&self) -> SignalFuture<()> {
+        object_signal(self, "text_set").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn caret_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "caret_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_added_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "gutter_added").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_clicked_fallible(&self) -> FallibleSignalFuture<(i64, i64)> {
+        object_signal(self, "gutter_clicked").to_fallible_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn gutter_removed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "gutter_removed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn lines_edited_from_fallible(&self) -> FallibleSignalFuture<(i64, i64)> {
+        object_signal(self, "lines_edited_from").to_fallible_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "text_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn text_set_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "text_set").to_fallible_future::<()>()
+    }
+}
+
+pub trait CodeEditExt<T>
+where
+    T: Inherits<CodeEdit>,
+{
+    /// Emitted when a breakpoint is added or removed from a line.
+    /// If the line is moved via backspace a removed is emitted at the old line.
+    /// # Return
+    /// (`line`: i64,)
+    fn breakpoint_toggled(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when the user requests code completion.
+    fn code_completion_requested(&self) -> SignalFuture<()>;
+    /// Emitted when the user has clicked on a valid symbol.
+    /// # Return
+    /// (`symbol`: GString, `line`: i64, `column`: i64,)
+    fn symbol_lookup(&self) -> SignalFuture<(GString, i64, i64)>;
+    /// Emitted when the user hovers over a symbol. The symbol should be validated and responded to,
+    /// by calling `set_symbol_lookup_word_as_valid()`.
+    /// # Return
+    /// (`symbol`: GString,)
+    fn symbol_validate(&self) -> SignalFuture<(GString,)>;
+    /// `breakpoint_toggled`fallible
+    fn breakpoint_toggled_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `code_completion_requested`fallible
+    fn code_completion_requested_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `symbol_lookup`fallible
+    fn symbol_lookup_fallible(&self) -> FallibleSignalFuture<(GString, i64, i64)>;
+    /// `symbol_validate`fallible
+    fn symbol_validate_fallible(&self) -> FallibleSignalFuture<(GString,)>;
+}
+
+impl<T> CodeEditExt<T> for Gd<T>
+where
+    T: Inherits<CodeEdit>,
+{
+    #[inline]
+    #[track_caller]
+    fn breakpoint_toggled(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "breakpoint_toggled").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn code_completion_requested(&self) -> SignalFuture<()> {
+        object_signal(self, "code_completion_requested").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn symbol_lookup(&self) -> SignalFuture<(GString, i64, i64)> {
+        object_signal(self, "symbol_lookup").to_future::<(GString, i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn symbol_validate(&self) -> SignalFuture<(GString,)> {
+        object_signal(self, "symbol_validate").to_future::<(GString,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn breakpoint_toggled_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "breakpoint_toggled").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn code_completion_requested_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "code_completion_requested").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn symbol_lookup_fallible(&self) -> FallibleSignalFuture<(GString, i64, i64)> {
+        object_signal(self, "symbol_lookup").to_fallible_future::<(GString, i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn symbol_validate_fallible(&self) -> FallibleSignalFuture<(GString,)> {
+        object_signal(self, "symbol_validate").to_fallible_future::<(GString,)>()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::Timer;
 
 pub trait TimerExt<T>
@@ -14,11 +15,18 @@ where
     T: Inherits<Timer>,
 {
     #[inline]
+    #[track_caller]
     fn timeout(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "timeout").to_future::<()>()
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check(self, "timeout", crate::diagnostics::timer_check);
+        object_signal(self, "timeout").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn timeout_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "timeout").to_fallible_future::<()>()
+        let timeout = object_signal(self, "timeout").to_fallible_future::<()>();
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check_fallible(self, "timeout", crate::diagnostics::timer_check);
+        timeout
     }
 }
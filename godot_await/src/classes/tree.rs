@@ -0,0 +1,245 @@
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::Vector2;
+use godot::classes::{Tree, TreeItem};
+
+pub trait TreeExt<T>
+where
+    T: Inherits<Tree>,
+{
+    /// Emitted when a button on the tree was pressed (see `TreeItem.add_button()`).
+    /// # Return
+    /// (`item`: Gd<TreeItem>, `column`: i64, `id`: i64, `mouse_button_index`: i64,)
+    fn button_clicked(&self) -> SignalFuture<(Gd<TreeItem>, i64, i64, i64)>;
+    /// Emitted when a cell is selected.
+    fn cell_selected(&self) -> SignalFuture<()>;
+    /// Emitted when `TreeItem.propagate_check()` is called. Connect to this signal to process the items that are affected when `TreeItem.propagate_check()` is invoked.
+    /// The order that the items affected will be processed is as follows: the item that invoked the method, children of that item, and finally parents of that item.
+    /// # Return
+    /// (`item`: Gd<TreeItem>, `column`: i64,)
+    fn check_propagated_to_item(&self) -> SignalFuture<(Gd<TreeItem>, i64)>;
+    /// Emitted when a column's title is clicked with either `MOUSE_BUTTON_LEFT` or `MOUSE_BUTTON_RIGHT`.
+    /// # Return
+    /// (`column`: i64, `mouse_button_index`: i64,)
+    fn column_title_clicked(&self) -> SignalFuture<(i64, i64)>;
+    /// Emitted when an item with `TreeItem.CELL_MODE_CUSTOM` is clicked with a mouse button.
+    /// # Return
+    /// (`mouse_button_index`: i64,)
+    fn custom_item_clicked(&self) -> SignalFuture<(i64,)>;
+    /// Emitted when a cell with the `TreeItem.CELL_MODE_CUSTOM` is clicked to be edited.
+    /// # Return
+    /// (`arrow_clicked`: bool,)
+    fn custom_popup_edited(&self) -> SignalFuture<(bool,)>;
+    /// Emitted when a mouse button is clicked in the empty space of the tree.
+    /// # Return
+    /// (`click_position`: Vector2, `mouse_button_index`: i64,)
+    fn empty_clicked(&self) -> SignalFuture<(Vector2, i64)>;
+    /// Emitted when an item is double-clicked, or selected with a `ui_accept` input event (e.g. using Enter or Space on the keyboard).
+    fn item_activated(&self) -> SignalFuture<()>;
+    /// Emitted when an item is expanded or collapsed by clicking on the folding arrow or through code.
+    /// # Return
+    /// (`item`: Gd<TreeItem>,)
+    fn item_collapsed(&self) -> SignalFuture<(Gd<TreeItem>,)>;
+    /// Emitted when an item is edited.
+    fn item_edited(&self) -> SignalFuture<()>;
+    /// Emitted when an item's icon is double-clicked. For a signal that emits when any part of the item is double-clicked, see `item_activated`.
+    fn item_icon_double_clicked(&self) -> SignalFuture<()>;
+    /// Emitted when an item is selected with a mouse button.
+    /// # Return
+    /// (`mouse_position`: Vector2, `mouse_button_index`: i64,)
+    fn item_mouse_selected(&self) -> SignalFuture<(Vector2, i64)>;
+    /// Emitted when an item is selected.
+    fn item_selected(&self) -> SignalFuture<()>;
+    /// Emitted instead of `item_selected` if `select_mode` is set to `SELECT_MULTI`.
+    /// # Return
+    /// (`item`: Gd<TreeItem>, `column`: i64, `selected`: bool,)
+    fn multi_selected(&self) -> SignalFuture<(Gd<TreeItem>, i64, bool)>;
+    /// Emitted when a left mouse button click does not select any item.
+    fn nothing_selected(&self) -> SignalFuture<()>;
+    /// `button_clicked`fallible
+    fn button_clicked_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64, i64, i64)>;
+    /// `cell_selected`fallible
+    fn cell_selected_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `check_propagated_to_item`fallible
+    fn check_propagated_to_item_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64)>;
+    /// `column_title_clicked`fallible
+    fn column_title_clicked_fallible(&self) -> FallibleSignalFuture<(i64, i64)>;
+    /// `custom_item_clicked`fallible
+    fn custom_item_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+    /// `custom_popup_edited`fallible
+    fn custom_popup_edited_fallible(&self) -> FallibleSignalFuture<(bool,)>;
+    /// `empty_clicked`fallible
+    fn empty_clicked_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)>;
+    /// `item_activated`fallible
+    fn item_activated_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `item_collapsed`fallible
+    fn item_collapsed_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>,)>;
+    /// `item_edited`fallible
+    fn item_edited_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `item_icon_double_clicked`fallible
+    fn item_icon_double_clicked_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `item_mouse_selected`fallible
+    fn item_mouse_selected_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)>;
+    /// `item_selected`fallible
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `multi_selected`fallible
+    fn multi_selected_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64, bool)>;
+    /// `nothing_selected`fallible
+    fn nothing_selected_fallible(&self) -> FallibleSignalFuture<()>;
+}
+
+impl<T> TreeExt<T> for Gd<T>
+where
+    T: Inherits<Tree>,
+{
+    #[inline]
+    #[track_caller]
+    fn button_clicked(&self) -> SignalFuture<(Gd<TreeItem>, i64, i64, i64)> {
+        object_signal(self, "button_clicked").to_future::<(Gd<TreeItem>, i64, i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn cell_selected(&self) -> SignalFuture<()> {
+        object_signal(self, "cell_selected").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn check_propagated_to_item(&self) -> SignalFuture<(Gd<TreeItem>, i64)> {
+        object_signal(self, "check_propagated_to_item").to_future::<(Gd<TreeItem>, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn column_title_clicked(&self) -> SignalFuture<(i64, i64)> {
+        object_signal(self, "column_title_clicked").to_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn custom_item_clicked(&self) -> SignalFuture<(i64,)> {
+        object_signal(self, "custom_item_clicked").to_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn custom_popup_edited(&self) -> SignalFuture<(bool,)> {
+        object_signal(self, "custom_popup_edited").to_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn empty_clicked(&self) -> SignalFuture<(Vector2, i64)> {
+        object_signal(self, "empty_clicked").to_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_activated(&self) -> SignalFuture<()> {
+        object_signal(self, "item_activated").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_collapsed(&self) -> SignalFuture<(Gd<TreeItem>,)> {
+        object_signal(self, "item_collapsed").to_future::<(Gd<TreeItem>,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_edited(&self) -> SignalFuture<()> {
+        object_signal(self, "item_edited").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_icon_double_clicked(&self) -> SignalFuture<()> {
+        object_signal(self, "item_icon_double_clicked").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_mouse_selected(&self) -> SignalFuture<(Vector2, i64)> {
+        object_signal(self, "item_mouse_selected").to_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected(&self) -> SignalFuture<()> {
+        object_signal(self, "item_selected").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn multi_selected(&self) -> SignalFuture<(Gd<TreeItem>, i64, bool)> {
+        object_signal(self, "multi_selected").to_future::<(Gd<TreeItem>, i64, bool)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn nothing_selected(&self) -> SignalFuture<()> {
+        object_signal(self, "nothing_selected").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn button_clicked_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64, i64, i64)> {
+        object_signal(self, "button_clicked").to_fallible_future::<(Gd<TreeItem>, i64, i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn cell_selected_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "cell_selected").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn check_propagated_to_item_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64)> {
+        object_signal(self, "check_propagated_to_item").to_fallible_future::<(Gd<TreeItem>, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn column_title_clicked_fallible(&self) -> FallibleSignalFuture<(i64, i64)> {
+        object_signal(self, "column_title_clicked").to_fallible_future::<(i64, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn custom_item_clicked_fallible(&self) -> FallibleSignalFuture<(i64,)> {
+        object_signal(self, "custom_item_clicked").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn custom_popup_edited_fallible(&self) -> FallibleSignalFuture<(bool,)> {
+        object_signal(self, "custom_popup_edited").to_fallible_future::<(bool,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn empty_clicked_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)> {
+        object_signal(self, "empty_clicked").to_fallible_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_activated_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "item_activated").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_collapsed_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>,)> {
+        object_signal(self, "item_collapsed").to_fallible_future::<(Gd<TreeItem>,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_edited_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "item_edited").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_icon_double_clicked_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "item_icon_double_clicked").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_mouse_selected_fallible(&self) -> FallibleSignalFuture<(Vector2, i64)> {
+        object_signal(self, "item_mouse_selected").to_fallible_future::<(Vector2, i64)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn item_selected_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "item_selected").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn multi_selected_fallible(&self) -> FallibleSignalFuture<(Gd<TreeItem>, i64, bool)> {
+        object_signal(self, "multi_selected").to_fallible_future::<(Gd<TreeItem>, i64, bool)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn nothing_selected_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "nothing_selected").to_fallible_future::<()>()
+    }
+}
@@ -1,5 +1,14 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
-use godot::classes::Tween;
+use crate::utils::{next_process_frame, object_signal};
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+use godot::builtin::{NodePath, Variant};
+use godot::classes::tween::{EaseType, TransitionType};
+use godot::classes::{Node, PropertyTweener, Tween};
+use godot::meta::{AsArg, ToGodot};
+use std::error::Error;
+use std::future::{Future, IntoFuture};
 
 pub trait TweenExt<T>
 where
@@ -23,6 +32,12 @@ where
     /// (`loop_count`: i64, )
     fn loop_finished(&self) -> SignalFuture<(i64,)>;
     fn loop_finished_fallible(&self) -> FallibleSignalFuture<(i64,)>;
+
+    /// Like `finished`, but resolves to `Err(TweenKilled)` once the Tween becomes invalid,
+    /// e.g. after `kill()` or when its bound node is freed, instead of never resolving.
+    ///
+    /// Validity is checked every process frame.
+    fn finished_or_killed(&self) -> TweenFuture;
 }
 
 impl<T> TweenExt<T> for Gd<T>
@@ -30,27 +45,228 @@ where
     T: Inherits<Tween>,
 {
     #[inline]
+    #[track_caller]
     fn finished(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "finished").to_future::<()>()
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check(self, "finished", crate::diagnostics::tween_check);
+        object_signal(self, "finished").to_future::<()>()
     }
     #[inline]
+    #[track_caller]
     fn finished_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "finished").to_fallible_future::<()>()
+        let finished = object_signal(self, "finished").to_fallible_future::<()>();
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::defer_check_fallible(self, "finished", crate::diagnostics::tween_check);
+        finished
     }
     #[inline]
+    #[track_caller]
     fn loop_finished(&self) -> SignalFuture<(i64,)> {
-        Signal::from_object_signal(self, "loop_finished").to_future::<(i64,)>()
+        object_signal(self, "loop_finished").to_future::<(i64,)>()
     }
     #[inline]
+    #[track_caller]
     fn loop_finished_fallible(&self) -> FallibleSignalFuture<(i64,)> {
-        Signal::from_object_signal(self, "loop_finished").to_fallible_future::<(i64,)>()
+        object_signal(self, "loop_finished").to_fallible_future::<(i64,)>()
     }
     #[inline]
+    #[track_caller]
     fn step_finished(&self) -> SignalFuture<(i64,)> {
-        Signal::from_object_signal(self, "step_finished").to_future::<(i64,)>()
+        object_signal(self, "step_finished").to_future::<(i64,)>()
     }
     #[inline]
+    #[track_caller]
     fn step_finished_fallible(&self) -> FallibleSignalFuture<(i64,)> {
-        Signal::from_object_signal(self, "step_finished").to_fallible_future::<(i64,)>()
+        object_signal(self, "step_finished").to_fallible_future::<(i64,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn finished_or_killed(&self) -> TweenFuture {
+        TweenFuture::new(self.clone().upcast())
+    }
+}
+
+/// Error returned when a `Tween` is killed or invalidated before emitting `finished`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenKilled;
+
+impl fmt::Display for TweenKilled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tween was killed before it finished")
+    }
+}
+
+impl Error for TweenKilled {}
+
+/// Future for `TweenExt::finished_or_killed` and awaiting a `TweenBuilder`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct TweenFuture {
+    tween: Gd<Tween>,
+    finished: FallibleSignalFuture<()>,
+    next_frame: Option<SignalFuture<()>>,
+}
+
+impl TweenFuture {
+    #[track_caller]
+    fn new(tween: Gd<Tween>) -> Self {
+        // Not `finished_fallible()`: a killed Tween is expected here and must not be reported by diagnostics.
+        let finished = object_signal(&tween, "finished").to_fallible_future::<()>();
+        TweenFuture {
+            tween,
+            finished,
+            next_frame: None,
+        }
+    }
+
+    /// The awaited Tween.
+    pub fn tween(&self) -> Gd<Tween> {
+        self.tween.clone()
+    }
+}
+
+impl Future for TweenFuture {
+    type Output = Result<(), TweenKilled>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        loop {
+            if let Poll::Ready(result) = Pin::new(&mut this.finished).poll(cx) {
+                return Poll::Ready(result.map_err(|_| TweenKilled));
+            }
+            if !this.tween.is_valid() {
+                return Poll::Ready(Err(TweenKilled));
+            }
+
+            let next_frame = this.next_frame.get_or_insert_with(next_process_frame);
+            ready!(Pin::new(next_frame).poll(cx));
+            this.next_frame = None;
+        }
+    }
+}
+
+/// Builder for tweening properties of a node, created by `NodeExt::tween_property_async`.
+///
+/// Every setting applies to the most recently added property, mirroring `PropertyTweener`.
+/// Await the builder (or call `done()`) to wait until all properties finished tweening.
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// node.tween_property_async("position", Vector2::new(100.0, 0.0), 1.0)
+///     .trans(TransitionType::SINE)
+///     .ease(EaseType::OUT)
+///     .parallel()
+///     .property("modulate:a", 0.0, 0.5)
+///     .delay(0.5)
+///     .await?;
+/// ```
+#[must_use = "the tween runs regardless, but its end is only observed when awaited"]
+pub struct TweenBuilder {
+    tween: Gd<Tween>,
+    object: Gd<Node>,
+    last: Gd<PropertyTweener>,
+    parallel: bool,
+}
+
+impl TweenBuilder {
+    pub(crate) fn new(
+        object: Gd<Node>,
+        property: impl AsArg<NodePath>,
+        final_val: &Variant,
+        duration_sec: f64,
+    ) -> Self {
+        let tween = object
+            .clone()
+            .create_tween()
+            .expect("ERR(godot_await):create Tween failed");
+        TweenBuilder {
+            last: Self::tween_property(tween.clone(), &object, property, final_val, duration_sec),
+            tween,
+            object,
+            parallel: false,
+        }
+    }
+
+    fn tween_property(
+        mut tween: Gd<Tween>,
+        object: &Gd<Node>,
+        property: impl AsArg<NodePath>,
+        final_val: &Variant,
+        duration_sec: f64,
+    ) -> Gd<PropertyTweener> {
+        tween
+            .tween_property(object, property, final_val, duration_sec)
+            .expect("ERR(godot_await):create PropertyTweener failed")
+    }
+
+    /// Set the transition type of the last property.
+    pub fn trans(mut self, trans: TransitionType) -> Self {
+        self.last.set_trans(trans);
+        self
+    }
+
+    /// Set the easing type of the last property.
+    pub fn ease(mut self, ease: EaseType) -> Self {
+        self.last.set_ease(ease);
+        self
+    }
+
+    /// Delay the start of the last property by `delay_sec` seconds.
+    pub fn delay(mut self, delay_sec: f64) -> Self {
+        self.last.set_delay(delay_sec);
+        self
+    }
+
+    /// Run the next added property at the same time as the last one, instead of after it.
+    pub fn parallel(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    /// Tween another property of the same node, after the last one unless `parallel()` was called.
+    pub fn property(
+        mut self,
+        property: impl AsArg<NodePath>,
+        final_val: impl ToGodot,
+        duration_sec: f64,
+    ) -> Self {
+        if std::mem::take(&mut self.parallel) {
+            self.tween.parallel();
+        }
+        self.last = Self::tween_property(
+            self.tween.clone(),
+            &self.object,
+            property,
+            &final_val.to_variant(),
+            duration_sec,
+        );
+        self
+    }
+
+    /// The underlying Tween, e.g. to `kill()` it.
+    pub fn tween(&self) -> Gd<Tween> {
+        self.tween.clone()
+    }
+
+    /// Finalize the builder.
+    ///
+    /// # Returns
+    /// A future that resolves when the Tween finished, or to `Err(TweenKilled)` if it was killed first.
+    #[inline]
+    #[track_caller]
+    pub fn done(self) -> TweenFuture {
+        TweenFuture::new(self.tween)
+    }
+}
+
+impl IntoFuture for TweenBuilder {
+    type Output = Result<(), TweenKilled>;
+    type IntoFuture = TweenFuture;
+
+    #[inline]
+    #[track_caller]
+    fn into_future(self) -> Self::IntoFuture {
+        self.done()
     }
 }
@@ -1,4 +1,5 @@
-use crate::{FallibleSignalFuture, Gd, Inherits, Signal, SignalFuture};
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
 use godot::classes::{Control, Viewport};
 
 pub trait ViewportExt<T>
@@ -22,22 +23,26 @@ where
     T: Inherits<Viewport>,
 {
     #[inline]
+    #[track_caller]
     fn size_changed(&self) -> SignalFuture<()> {
-        Signal::from_object_signal(self, "size_changed").to_future::<()>()
+        object_signal(self, "size_changed").to_future::<()>()
     }
 
     #[inline]
+    #[track_caller]
     fn gui_focus_changed(&self) -> SignalFuture<(Gd<Control>,)> {
-        Signal::from_object_signal(self, "gui_focus_changed").to_future::<(Gd<Control>,)>()
+        object_signal(self, "gui_focus_changed").to_future::<(Gd<Control>,)>()
     }
 
     #[inline]
+    #[track_caller]
     fn size_changed_fallible(&self) -> FallibleSignalFuture<()> {
-        Signal::from_object_signal(self, "size_changed").to_fallible_future::<()>()
+        object_signal(self, "size_changed").to_fallible_future::<()>()
     }
 
     #[inline]
+    #[track_caller]
     fn gui_focus_changed_fallible(&self) -> FallibleSignalFuture<(Gd<Control>,)> {
-        Signal::from_object_signal(self, "gui_focus_changed").to_fallible_future::<(Gd<Control>,)>()
+        object_signal(self, "gui_focus_changed").to_fallible_future::<(Gd<Control>,)>()
     }
 }
@@ -0,0 +1,150 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::object_signal;
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::classes::{
+    Node, VisibleOnScreenEnabler2D, VisibleOnScreenEnabler3D, VisibleOnScreenNotifier2D,
+    VisibleOnScreenNotifier3D,
+};
+use godot::task::FallibleSignalFutureError;
+
+trait VisibleOnScreenSignal: Inherits<Node> {
+    /// Whether the bounding rectangle (or box) of the notifier is visible on screen.
+    fn is_on_screen(notifier: &Gd<Self>) -> bool;
+}
+
+impl VisibleOnScreenSignal for VisibleOnScreenNotifier2D {
+    fn is_on_screen(notifier: &Gd<Self>) -> bool {
+        notifier.is_on_screen()
+    }
+}
+impl VisibleOnScreenSignal for VisibleOnScreenNotifier3D {
+    fn is_on_screen(notifier: &Gd<Self>) -> bool {
+        notifier.is_on_screen()
+    }
+}
+impl VisibleOnScreenSignal for VisibleOnScreenEnabler2D {
+    fn is_on_screen(notifier: &Gd<Self>) -> bool {
+        notifier.is_on_screen()
+    }
+}
+impl VisibleOnScreenSignal for VisibleOnScreenEnabler3D {
+    fn is_on_screen(notifier: &Gd<Self>) -> bool {
+        notifier.is_on_screen()
+    }
+}
+
+pub trait VisibleOnScreenExt<T>
+where
+    T: Inherits<Node>,
+{
+    /// Emitted when the notifier enters the screen.
+    fn screen_entered(&self) -> SignalFuture<()>;
+    /// Emitted when the notifier exits the screen.
+    fn screen_exited(&self) -> SignalFuture<()>;
+    /// `screen_entered`fallible
+    fn screen_entered_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `screen_exited`fallible
+    fn screen_exited_fallible(&self) -> FallibleSignalFuture<()>;
+
+    /// Resolves once the notifier is on screen, right away if it already is.
+    ///
+    /// Note: the state is only known after the notifier was drawn once, a newly added notifier is off screen
+    /// until then.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// notifier.wait_on_screen().await;
+    /// enemy.start_attacking();
+    /// ```
+    fn wait_on_screen(&self) -> ScreenState<SignalFuture<()>>;
+    /// `wait_on_screen`fallible
+    fn wait_on_screen_fallible(&self) -> ScreenState<FallibleSignalFuture<()>>;
+    /// Resolves once the notifier is off screen, right away if it already is.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// notifier.wait_off_screen().await;
+    /// bullet.queue_free();
+    /// ```
+    fn wait_off_screen(&self) -> ScreenState<SignalFuture<()>>;
+    /// `wait_off_screen`fallible
+    fn wait_off_screen_fallible(&self) -> ScreenState<FallibleSignalFuture<()>>;
+}
+
+impl<T> VisibleOnScreenExt<T> for Gd<T>
+where
+    T: VisibleOnScreenSignal,
+{
+    #[inline]
+    #[track_caller]
+    fn screen_entered(&self) -> SignalFuture<()> {
+        object_signal(self, "screen_entered").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn screen_exited(&self) -> SignalFuture<()> {
+        object_signal(self, "screen_exited").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn screen_entered_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "screen_entered").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn screen_exited_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "screen_exited").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_on_screen(&self) -> ScreenState<SignalFuture<()>> {
+        ScreenState((!T::is_on_screen(self)).then(|| self.screen_entered()))
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_on_screen_fallible(&self) -> ScreenState<FallibleSignalFuture<()>> {
+        ScreenState((!T::is_on_screen(self)).then(|| self.screen_entered_fallible()))
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_off_screen(&self) -> ScreenState<SignalFuture<()>> {
+        ScreenState(T::is_on_screen(self).then(|| self.screen_exited()))
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_off_screen_fallible(&self) -> ScreenState<FallibleSignalFuture<()>> {
+        ScreenState(T::is_on_screen(self).then(|| self.screen_exited_fallible()))
+    }
+}
+
+/// Future for `VisibleOnScreenExt::wait_on_screen`, `VisibleOnScreenExt::wait_off_screen` and their `_fallible`
+/// variants, holding no signal future if the notifier already was in the requested state.
+///
+/// Resolves to `()`, or to `Result<(), FallibleSignalFutureError>` for the `_fallible` variants.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ScreenState<F>(Option<F>);
+
+impl Future for ScreenState<SignalFuture<()>> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.0 {
+            Some(signal) => Pin::new(signal).poll(cx),
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+impl Future for ScreenState<FallibleSignalFuture<()>> {
+    type Output = Result<(), FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.0 {
+            Some(signal) => Pin::new(signal).poll(cx),
+            None => Poll::Ready(Ok(())),
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::utils::{object_signal, try_godot_tree};
+use crate::{FallibleSignalFuture, Gd, Inherits, SignalFuture};
+use godot::builtin::PackedStringArray;
+use godot::classes::{InputEvent, Window};
+use godot::task::FallibleSignalFutureError;
+
+pub trait WindowExt<T>
+where
+    T: Inherits<Window>,
+{
+    /// Emitted right after `popup()` call, before the Window appears or does anything.
+    fn about_to_popup(&self) -> SignalFuture<()>;
+    /// Emitted when the Window's close button is pressed or when `popup_window` is enabled and user clicks outside the window.
+    ///
+    /// This signal can be used to handle window closing, e.g. by connecting it to `hide()`.
+    fn close_requested(&self) -> SignalFuture<()>;
+    /// Emitted when the Window's DPI changes as a result of OS-level changes (e.g. moving the window from a Retina display to a lower resolution one).
+    ///
+    /// Note: Only implemented on macOS.
+    fn dpi_changed(&self) -> SignalFuture<()>;
+    /// Emitted when files are dragged from the OS file manager and dropped in the game window. The argument is a list of file paths.
+    ///
+    /// Note: This signal only works with native windows, i.e. the main window and Window-derived nodes when `Viewport.gui_embed_subwindows` is disabled in the main viewport.
+    /// # Return
+    /// (`files`: PackedStringArray,)
+    fn files_dropped(&self) -> SignalFuture<(PackedStringArray,)>;
+    /// Emitted when the Window gains focus.
+    fn focus_entered(&self) -> SignalFuture<()>;
+    /// Emitted when the Window loses its focus.
+    fn focus_exited(&self) -> SignalFuture<()>;
+    /// Emitted when a go back request is sent (e.g. pressing the "Back" button on Android), right after `Node.NOTIFICATION_WM_GO_BACK_REQUEST`.
+    fn go_back_requested(&self) -> SignalFuture<()>;
+    /// Emitted when the mouse cursor enters the Window's visible area, that is not occluded behind other Controls or windows, provided its `Viewport.gui_disable_input` is false and regardless if it's currently focused or not.
+    fn mouse_entered(&self) -> SignalFuture<()>;
+    /// Emitted when the mouse cursor leaves the Window's visible area, that is not occluded behind other Controls or windows, provided its `Viewport.gui_disable_input` is false and regardless if it's currently focused or not.
+    fn mouse_exited(&self) -> SignalFuture<()>;
+    /// Emitted when the `NOTIFICATION_THEME_CHANGED` notification is sent.
+    fn theme_changed(&self) -> SignalFuture<()>;
+    /// Emitted when window title bar decorations are changed, e.g. macOS window enter/exit full screen mode, or extend-to-title flag is changed.
+    fn titlebar_changed(&self) -> SignalFuture<()>;
+    /// Emitted when Window is made visible or disappears.
+    fn visibility_changed(&self) -> SignalFuture<()>;
+    /// Emitted when the Window is currently focused and receives any input, passing the received event as an argument.
+    /// The event's position, if present, is in the embedder's coordinate system.
+    /// # Return
+    /// (`event`: Gd<InputEvent>,)
+    fn window_input(&self) -> SignalFuture<(Gd<InputEvent>,)>;
+    /// `about_to_popup`fallible
+    fn about_to_popup_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `close_requested`fallible
+    fn close_requested_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `dpi_changed`fallible
+    fn dpi_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `files_dropped`fallible
+    fn files_dropped_fallible(&self) -> FallibleSignalFuture<(PackedStringArray,)>;
+    /// `focus_entered`fallible
+    fn focus_entered_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `focus_exited`fallible
+    fn focus_exited_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `go_back_requested`fallible
+    fn go_back_requested_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `mouse_entered`fallible
+    fn mouse_entered_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `mouse_exited`fallible
+    fn mouse_exited_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `theme_changed`fallible
+    fn theme_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `titlebar_changed`fallible
+    fn titlebar_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `visibility_changed`fallible
+    fn visibility_changed_fallible(&self) -> FallibleSignalFuture<()>;
+    /// `window_input`fallible
+    fn window_input_fallible(&self) -> FallibleSignalFuture<(Gd<InputEvent>,)>;
+
+    /// Resolves when the player asks to close the Window (close button, or a click outside with `popup_window`).
+    ///
+    /// The Window stays open, as a plain Window does on `close_requested`; see
+    /// [`close_on_request`](Self::close_on_request) to hide it as well.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// quit_dialog.wait_close_requested().await;
+    /// if confirm_quit().await {
+    ///     quit_dialog.hide();
+    /// }
+    /// ```
+    fn wait_close_requested(&self) -> SignalFuture<()>;
+    /// `wait_close_requested`fallible
+    fn wait_close_requested_fallible(&self) -> FallibleSignalFuture<()>;
+    /// Resolves when the player asks to close the Window (close button, or a click outside with `popup_window`),
+    /// and hides it then. A plain Window stays open on `close_requested` unless hidden by hand;
+    /// await [`wait_close_requested`](Self::wait_close_requested) instead to decide yourself.
+    ///
+    /// The root window cannot be hidden, so it is left as is.
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// settings_window.popup_centered();
+    /// settings_window.close_on_request().await;
+    /// save_settings();
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if the Window is freed before
+    fn close_on_request(&self) -> CloseOnRequest<SignalFuture<()>>;
+    /// `close_on_request`fallible
+    fn close_on_request_fallible(&self) -> CloseOnRequest<FallibleSignalFuture<()>>;
+}
+
+impl<T> WindowExt<T> for Gd<T>
+where
+    T: Inherits<Window>,
+{
+    #[inline]
+    #[track_caller]
+    fn about_to_popup(&self) -> SignalFuture<()> {
+        object_signal(self, "about_to_popup").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn close_requested(&self) -> SignalFuture<()> {
+        object_signal(self, "close_requested").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn dpi_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "dpi_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn files_dropped(&self) -> SignalFuture<(PackedStringArray,)> {
+        object_signal(self, "files_dropped").to_future::<(PackedStringArray,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn focus_entered(&self) -> SignalFuture<()> {
+        object_signal(self, "focus_entered").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn focus_exited(&self) -> SignalFuture<()> {
+        object_signal(self, "focus_exited").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn go_back_requested(&self) -> SignalFuture<()> {
+        object_signal(self, "go_back_requested").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn mouse_entered(&self) -> SignalFuture<()> {
+        object_signal(self, "mouse_entered").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn mouse_exited(&self) -> SignalFuture<()> {
+        object_signal(self, "mouse_exited").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn theme_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "theme_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn titlebar_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "titlebar_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn visibility_changed(&self) -> SignalFuture<()> {
+        object_signal(self, "visibility_changed").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn window_input(&self) -> SignalFuture<(Gd<InputEvent>,)> {
+        object_signal(self, "window_input").to_future::<(Gd<InputEvent>,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn about_to_popup_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "about_to_popup").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn close_requested_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "close_requested").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn dpi_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "dpi_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn files_dropped_fallible(&self) -> FallibleSignalFuture<(PackedStringArray,)> {
+        object_signal(self, "files_dropped").to_fallible_future::<(PackedStringArray,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn focus_entered_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "focus_entered").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn focus_exited_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "focus_exited").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn go_back_requested_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "go_back_requested").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn mouse_entered_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "mouse_entered").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn mouse_exited_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "mouse_exited").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn theme_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "theme_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn titlebar_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "titlebar_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn visibility_changed_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "visibility_changed").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn window_input_fallible(&self) -> FallibleSignalFuture<(Gd<InputEvent>,)> {
+        object_signal(self, "window_input").to_fallible_future::<(Gd<InputEvent>,)>()
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_close_requested(&self) -> SignalFuture<()> {
+        object_signal(self, "close_requested").to_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn wait_close_requested_fallible(&self) -> FallibleSignalFuture<()> {
+        object_signal(self, "close_requested").to_fallible_future::<()>()
+    }
+    #[inline]
+    #[track_caller]
+    fn close_on_request(&self) -> CloseOnRequest<SignalFuture<()>> {
+        CloseOnRequest {
+            window: self.clone().upcast(),
+            requested: self.close_requested(),
+        }
+    }
+    #[inline]
+    #[track_caller]
+    fn close_on_request_fallible(&self) -> CloseOnRequest<FallibleSignalFuture<()>> {
+        CloseOnRequest {
+            window: self.clone().upcast(),
+            requested: self.close_requested_fallible(),
+        }
+    }
+}
+
+/// Future for `WindowExt::close_on_request` and `WindowExt::close_on_request_fallible`.
+///
+/// Awaits `close_requested`, then hides the Window (unless it is the root window) in the same poll that resolves,
+/// so the Window is already hidden when the awaiting code resumes. Dropping the future before leaves the Window as is.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CloseOnRequest<F> {
+    window: Gd<Window>,
+    requested: F,
+}
+
+impl Future for CloseOnRequest<SignalFuture<()>> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        ready_then_hide(&mut this.window, Pin::new(&mut this.requested).poll(cx))
+    }
+}
+
+impl Future for CloseOnRequest<FallibleSignalFuture<()>> {
+    type Output = Result<(), FallibleSignalFutureError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        match Pin::new(&mut this.requested).poll(cx) {
+            Poll::Ready(Ok(())) => ready_then_hide(&mut this.window, Poll::Ready(())).map(Ok),
+            Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+fn ready_then_hide(window: &mut Gd<Window>, poll: Poll<()>) -> Poll<()> {
+    if poll.is_ready() && window.is_instance_valid() && !is_root(window) {
+        window.hide();
+    }
+    poll
+}
+
+fn is_root(window: &Gd<Window>) -> bool {
+    try_godot_tree()
+        .and_then(|tree| tree.get_root())
+        .is_some_and(|root| root == *window)
+}
@@ -0,0 +1,250 @@
+//! Editor debugger tab listing pending awaits, enabled by the `editor` feature.
+//!
+//! The game answers `godot_await:request` messages with a `godot_await:tasks` message, containing the
+//! [registry](crate::registry) serialized by [`serialize`]. Call [`install`] once at startup, e.g. in an autoload's `ready()`.
+//!
+//! In the editor, [`AwaitEditorPlugin`] adds an "Async Tasks" tab to the debugger, refreshed every second.
+
+use std::collections::HashMap;
+
+use godot::builtin::{Callable, Dictionary, GString, Variant, VariantArray};
+use godot::classes::{
+    EditorDebuggerPlugin, EditorDebuggerSession, EditorPlugin, EngineDebugger,
+    IEditorDebuggerPlugin, IEditorPlugin, Node, Timer, Tree,
+};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::register::{GodotClass, godot_api};
+
+use crate::registry::{PendingAwait, pending_awaits};
+
+const CAPTURE: &str = "godot_await";
+const REQUEST_MESSAGE: &str = "godot_await:request";
+const TASKS_MESSAGE: &str = "godot_await:tasks";
+const COLUMNS: [&str; 5] = ["Task", "Signal", "Owner", "Pending (s)", "Created at"];
+
+/// A pending await, as exchanged between the game and the editor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskInfo {
+    /// Task name, empty for unnamed tasks.
+    pub task: String,
+    /// Class name of the emitter.
+    pub class: String,
+    /// Instance ID of the emitter.
+    pub object_id: i64,
+    /// Awaited signal.
+    pub signal: String,
+    /// Path of the emitter, if it is a node inside the tree; empty otherwise.
+    pub node_path: String,
+    /// Creation site, as `file:line:column`.
+    pub location: String,
+    /// Time pending, in seconds.
+    pub age_sec: f64,
+}
+
+impl TaskInfo {
+    /// Collects the information of `pending`, looking up the node path of its emitter.
+    pub fn from_pending(pending: &PendingAwait) -> Self {
+        let node_path = Gd::<Node>::try_from_instance_id(pending.object_id)
+            .ok()
+            .filter(|node| node.is_inside_tree())
+            .map(|node| node.get_path().to_string())
+            .unwrap_or_default();
+
+        TaskInfo {
+            task: pending.task.clone().unwrap_or_default(),
+            class: pending.class.clone(),
+            object_id: pending.object_id.to_i64(),
+            signal: pending.signal.clone(),
+            node_path,
+            location: pending.location.to_string(),
+            age_sec: pending.age().as_secs_f64(),
+        }
+    }
+
+    /// Converts to a dictionary with one key per field, the inverse of [`from_dictionary`](Self::from_dictionary).
+    pub fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("task", self.task.to_variant());
+        dict.set("class", self.class.to_variant());
+        dict.set("object_id", self.object_id);
+        dict.set("signal", self.signal.to_variant());
+        dict.set("node_path", self.node_path.to_variant());
+        dict.set("location", self.location.to_variant());
+        dict.set("age_sec", self.age_sec);
+        dict
+    }
+
+    /// Returns `None` if a key is missing or has the wrong type.
+    pub fn from_dictionary(dict: &Dictionary) -> Option<Self> {
+        let string = |key: &str| dict.get(key)?.try_to::<String>().ok();
+
+        Some(TaskInfo {
+            task: string("task")?,
+            class: string("class")?,
+            object_id: dict.get("object_id")?.try_to().ok()?,
+            signal: string("signal")?,
+            node_path: string("node_path")?,
+            location: string("location")?,
+            age_sec: dict.get("age_sec")?.try_to().ok()?,
+        })
+    }
+}
+
+/// Serializes pending awaits into the payload of a `godot_await:tasks` message.
+pub fn serialize(pending: &[PendingAwait]) -> VariantArray {
+    pending
+        .iter()
+        .map(|pending| TaskInfo::from_pending(pending).to_dictionary().to_variant())
+        .collect()
+}
+
+/// Deserializes the payload of a `godot_await:tasks` message, skipping malformed entries.
+pub fn deserialize(data: &VariantArray) -> Vec<TaskInfo> {
+    data.iter_shared()
+        .filter_map(|entry| entry.try_to::<Dictionary>().ok())
+        .filter_map(|dict| TaskInfo::from_dictionary(&dict))
+        .collect()
+}
+
+/// Sends all pending awaits to the editor, if the debugger is active.
+pub fn send_tasks() {
+    let mut debugger = EngineDebugger::singleton();
+    if debugger.is_active() {
+        debugger.send_message(TASKS_MESSAGE, &serialize(&pending_awaits()));
+    }
+}
+
+/// Answers task requests from the editor. Does nothing if the game was not started from the editor.
+pub fn install() {
+    let mut debugger = EngineDebugger::singleton();
+    if !debugger.is_active() || debugger.has_capture(CAPTURE) {
+        return;
+    }
+
+    let capture = Callable::from_local_fn("godot_await::debugger", |args| {
+        // Godot strips the capture prefix from the message.
+        let handled = args
+            .first()
+            .is_some_and(|message| message.to_string() == "request");
+        if handled {
+            send_tasks();
+        }
+        Ok(handled.to_variant())
+    });
+    debugger.register_message_capture(CAPTURE, &capture);
+}
+
+/// Debugger plugin showing the pending awaits of each debug session in an "Async Tasks" tab.
+#[derive(GodotClass)]
+#[class(tool, init, base=EditorDebuggerPlugin)]
+pub struct AwaitDebuggerPlugin {
+    trees: HashMap<i32, Gd<Tree>>,
+    base: Base<EditorDebuggerPlugin>,
+}
+
+#[godot_api]
+impl IEditorDebuggerPlugin for AwaitDebuggerPlugin {
+    fn setup_session(&mut self, session_id: i32) {
+        let Some(mut session) = self.base().get_session(session_id) else {
+            return;
+        };
+
+        let mut tree = Tree::new_alloc();
+        tree.set_name("Async Tasks");
+        tree.set_columns(COLUMNS.len() as i32);
+        tree.set_column_titles_visible(true);
+        tree.set_hide_root(true);
+        for (column, title) in COLUMNS.iter().enumerate() {
+            tree.set_column_title(column as i32, *title);
+        }
+
+        let mut timer = Timer::new_alloc();
+        timer.set_wait_time(1.0);
+        timer.set_autostart(true);
+        let session_ref = session.clone();
+        timer.connect(
+            "timeout",
+            &Callable::from_local_fn("godot_await::debugger::refresh", move |_| {
+                request_tasks(session_ref.clone());
+                Ok(Variant::nil())
+            }),
+        );
+        tree.add_child(&timer);
+
+        session.add_session_tab(&tree);
+        self.trees.insert(session_id, tree);
+    }
+
+    fn has_capture(&self, capture: GString) -> bool {
+        capture == CAPTURE.into()
+    }
+
+    fn capture(&mut self, message: GString, data: VariantArray, session_id: i32) -> bool {
+        if message != TASKS_MESSAGE.into() {
+            return false;
+        }
+        if let Some(tree) = self.trees.get_mut(&session_id) {
+            fill_tree(tree, &deserialize(&data));
+        }
+        true
+    }
+}
+
+fn request_tasks(mut session: Gd<EditorDebuggerSession>) {
+    if session.is_active() {
+        session.send_message(REQUEST_MESSAGE);
+    }
+}
+
+fn fill_tree(tree: &mut Gd<Tree>, tasks: &[TaskInfo]) {
+    tree.clear();
+    let Some(root) = tree.create_item() else {
+        return;
+    };
+
+    for task in tasks {
+        let Some(mut item) = tree.create_item_ex().parent(&root).done() else {
+            continue;
+        };
+        let name = if task.task.is_empty() {
+            "<unnamed>"
+        } else {
+            &task.task
+        };
+        let owner = if task.node_path.is_empty() {
+            format!("{}#{}", task.class, task.object_id)
+        } else {
+            task.node_path.clone()
+        };
+
+        item.set_text(0, name);
+        item.set_text(1, &task.signal);
+        item.set_text(2, &owner);
+        item.set_text(3, &format!("{:.1}", task.age_sec));
+        item.set_text(4, &task.location);
+    }
+}
+
+/// Editor plugin registering [`AwaitDebuggerPlugin`].
+#[derive(GodotClass)]
+#[class(tool, init, editor_plugin, base=EditorPlugin)]
+pub struct AwaitEditorPlugin {
+    debugger: Option<Gd<AwaitDebuggerPlugin>>,
+    base: Base<EditorPlugin>,
+}
+
+#[godot_api]
+impl IEditorPlugin for AwaitEditorPlugin {
+    fn enter_tree(&mut self) {
+        let debugger = AwaitDebuggerPlugin::new_gd();
+        self.base_mut().add_debugger_plugin(&debugger);
+        self.debugger = Some(debugger);
+    }
+
+    fn exit_tree(&mut self) {
+        if let Some(debugger) = self.debugger.take() {
+            self.base_mut().remove_debugger_plugin(&debugger);
+        }
+    }
+}
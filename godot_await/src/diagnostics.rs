@@ -0,0 +1,165 @@
+//! Detection of futures which can never resolve, enabled by the `diagnostics` feature.
+//!
+//! When enabled, `TweenExt::finished`, `TimerExt::timeout` and `AnimatedSpriteExt::animation_finished`
+//! (including their `_fallible` variants) check their source object at the end of the frame they were created in,
+//! and log a `godot_warn!` with the caller location if the signal can't be emitted anymore.
+//! The `_fallible` variants are then also disconnected, so they resolve to `Err` instead of never resolving.
+
+use core::fmt;
+use std::error::Error;
+use std::panic::Location;
+
+use godot::builtin::{Callable, StringName, Variant};
+use godot::classes::{Node, Object, SpriteFrames, Timer, Tween};
+use godot::global::godot_warn;
+use godot::obj::{Gd, GodotClass, Inherits};
+
+use crate::utils::last_connection;
+
+/// Reason why a signal future can never resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NeverResolves {
+    /// The Tween was killed, or has no tweeners.
+    TweenInvalid,
+    /// The Tween loops infinitely, so it never emits `finished`.
+    TweenInfiniteLoop,
+    /// The Timer is stopped, so it never emits `timeout`.
+    TimerStopped,
+    /// The animation is not playing.
+    AnimationNotPlaying,
+    /// The animation loops, so it never emits `animation_finished`.
+    AnimationLooping(StringName),
+}
+
+impl fmt::Display for NeverResolves {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TweenInvalid => f.write_str("the Tween is invalid (killed or empty)"),
+            Self::TweenInfiniteLoop => f.write_str("the Tween loops infinitely"),
+            Self::TimerStopped => f.write_str("the Timer is stopped"),
+            Self::AnimationNotPlaying => f.write_str("the animation is not playing"),
+            Self::AnimationLooping(name) => write!(f, "the animation `{name}` is looping"),
+        }
+    }
+}
+
+impl Error for NeverResolves {}
+
+/// Checks whether `tween` can still emit `finished`.
+pub(crate) fn check_tween<T: Inherits<Tween>>(tween: &Gd<T>) -> Result<(), NeverResolves> {
+    let tween = tween.clone().upcast::<Tween>();
+    if !tween.is_valid() {
+        Err(NeverResolves::TweenInvalid)
+    } else if tween.get_loops_left() == -1 {
+        Err(NeverResolves::TweenInfiniteLoop)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks whether `timer` can still emit `timeout`.
+pub(crate) fn check_timer<T: Inherits<Timer>>(timer: &Gd<T>) -> Result<(), NeverResolves> {
+    if timer.clone().upcast::<Timer>().is_stopped() {
+        Err(NeverResolves::TimerStopped)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks whether an `AnimatedSprite2D` or `AnimatedSprite3D` can still emit `animation_finished`.
+pub(crate) fn check_animated_sprite<T: Inherits<Node>>(
+    sprite: &Gd<T>,
+) -> Result<(), NeverResolves> {
+    let mut sprite = sprite.clone().upcast::<Node>();
+    if !sprite.call("is_playing", &[]).booleanize() {
+        return Err(NeverResolves::AnimationNotPlaying);
+    }
+
+    let animation = sprite.get("animation").to::<StringName>();
+    let looping = sprite
+        .get("sprite_frames")
+        .try_to::<Gd<SpriteFrames>>()
+        .is_ok_and(|frames| frames.get_animation_loop(&animation));
+    if looping {
+        Err(NeverResolves::AnimationLooping(animation))
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs `check` on `object` at the end of the current frame, warning if it fails.
+///
+/// Deferred, so that code like `let fut = timer.timeout(); timer.start();` is not reported.
+#[track_caller]
+pub(crate) fn defer_check<T: GodotClass>(
+    object: &Gd<T>,
+    signal: &'static str,
+    check: fn(&Gd<Object>) -> Result<(), NeverResolves>,
+) {
+    defer(object, signal, check, None);
+}
+
+/// Like [`defer_check`], for the fallible future created right before: if the check fails, the future is
+/// disconnected, so it resolves to `Err`.
+#[track_caller]
+pub(crate) fn defer_check_fallible<T: GodotClass>(
+    object: &Gd<T>,
+    signal: &'static str,
+    check: fn(&Gd<Object>) -> Result<(), NeverResolves>,
+) {
+    let connection = Gd::<Object>::try_from_instance_id(object.instance_id())
+        .ok()
+        .and_then(|object| last_connection(&object, signal));
+    defer(object, signal, check, connection);
+}
+
+#[track_caller]
+fn defer<T: GodotClass>(
+    object: &Gd<T>,
+    signal: &'static str,
+    check: fn(&Gd<Object>) -> Result<(), NeverResolves>,
+    mut connection: Option<Callable>,
+) {
+    let location = Location::caller();
+    let id = object.instance_id();
+
+    let callable = Callable::from_local_fn("godot_await::diagnostics", move |_| {
+        // Taken, so that the future's callable is released (and the future failed) right after disconnecting.
+        let connection = connection.take();
+        if let Ok(mut object) = Gd::<Object>::try_from_instance_id(id) {
+            if let Err(err) = check(&object) {
+                godot_warn!(
+                    "godot_await: `{signal}` awaited at {location} can never resolve: {err}"
+                );
+                if let Some(connection) = connection {
+                    if object.is_connected(signal, &connection) {
+                        object.disconnect(signal, &connection);
+                    }
+                }
+            }
+        }
+        Ok(Variant::nil())
+    });
+    callable.call_deferred(&[]);
+}
+
+pub(crate) fn tween_check(object: &Gd<Object>) -> Result<(), NeverResolves> {
+    object
+        .clone()
+        .try_cast::<Tween>()
+        .map_or(Ok(()), |tween| check_tween(&tween))
+}
+
+pub(crate) fn timer_check(object: &Gd<Object>) -> Result<(), NeverResolves> {
+    object
+        .clone()
+        .try_cast::<Timer>()
+        .map_or(Ok(()), |timer| check_timer(&timer))
+}
+
+pub(crate) fn animated_sprite_check(object: &Gd<Object>) -> Result<(), NeverResolves> {
+    object
+        .clone()
+        .try_cast::<Node>()
+        .map_or(Ok(()), |sprite| check_animated_sprite(&sprite))
+}
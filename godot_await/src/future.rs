@@ -6,12 +6,16 @@ use core::pin::Pin;
 
 use pin_project_lite::pin_project;
 
+use crate::cancel::{CancellationToken, WithCancel};
+use crate::stopwatch::Stopwatch;
+use crate::wait::{Wait, wait_pooled};
+
 use std::{
     any::Any,
     panic::{AssertUnwindSafe, UnwindSafe, catch_unwind},
 };
 
-use core::task::{Context, Poll};
+use core::task::{Context, Poll, ready};
 
 /// Joins two futures, waiting for both to complete.
 ///
@@ -233,6 +237,253 @@ where
     }
 }
 
+/// Delay policy between the attempts of [`retry_with_backoff()`].
+///
+/// All delays are in seconds and measured with pooled scene-tree timers, see [`wait_pooled()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backoff {
+    kind: BackoffKind,
+    jitter: f64,
+    max_delay: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BackoffKind {
+    Fixed(f64),
+    Linear { initial: f64, step: f64 },
+    Exponential { initial: f64, factor: f64 },
+}
+
+impl Backoff {
+    /// Waits `delay_sec` before every retry.
+    pub fn fixed(delay_sec: f64) -> Self {
+        Self::new(BackoffKind::Fixed(delay_sec))
+    }
+
+    /// Waits `initial_sec` before the first retry, increasing by `step_sec` for each following one.
+    pub fn linear(initial_sec: f64, step_sec: f64) -> Self {
+        Self::new(BackoffKind::Linear {
+            initial: initial_sec,
+            step: step_sec,
+        })
+    }
+
+    /// Waits `initial_sec` before the first retry, multiplying by `factor` for each following one.
+    pub fn exponential(initial_sec: f64, factor: f64) -> Self {
+        Self::new(BackoffKind::Exponential {
+            initial: initial_sec,
+            factor,
+        })
+    }
+
+    fn new(kind: BackoffKind) -> Self {
+        Backoff {
+            kind,
+            jitter: 0.0,
+            max_delay: None,
+        }
+    }
+
+    /// Randomizes every delay by up to `±fraction` of its value, using Godot's global RNG.
+    ///
+    /// # Arguments
+    /// * `fraction` - Clamped to `0.0..=1.0`, e.g. `0.2` turns a 1s delay into anything between 0.8s and 1.2s.
+    pub fn jitter(self, fraction: f64) -> Self {
+        Backoff {
+            jitter: fraction.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Caps every delay (before jitter) at `max_delay_sec`.
+    pub fn max_delay(self, max_delay_sec: f64) -> Self {
+        Backoff {
+            max_delay: Some(max_delay_sec),
+            ..self
+        }
+    }
+
+    /// Returns the delay in seconds before the retry with index `retry` (starting at 0), without jitter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use godot_await::future::Backoff;
+    ///
+    /// let backoff = Backoff::exponential(0.5, 2.0).max_delay(3.0);
+    /// assert_eq!(backoff.delay(0), 0.5);
+    /// assert_eq!(backoff.delay(2), 2.0);
+    /// assert_eq!(backoff.delay(3), 3.0);
+    /// ```
+    pub fn delay(&self, retry: u32) -> f64 {
+        let delay = match self.kind {
+            BackoffKind::Fixed(delay) => delay,
+            BackoffKind::Linear { initial, step } => initial + step * f64::from(retry),
+            BackoffKind::Exponential { initial, factor } => {
+                initial * factor.powi(retry.min(i32::MAX as u32) as i32)
+            }
+        };
+        let delay = self.max_delay.map_or(delay, |max| delay.min(max));
+        delay.max(0.0)
+    }
+
+    fn jittered_delay(&self, retry: u32) -> f64 {
+        let delay = self.delay(retry);
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let offset = godot::global::randf_range(-self.jitter, self.jitter);
+        (delay * (1.0 + offset)).max(0.0)
+    }
+}
+
+/// Runs the future returned by `make_future` up to `attempts` times, until it resolves to `Ok`.
+///
+/// Resolves to the last error if every attempt failed. `attempts` of 0 is treated as 1.
+///
+/// # Examples
+///
+/// ```
+/// use godot_await::future;
+///
+/// # spin_on::spin_on(async {
+/// let mut calls = 0;
+/// let result = future::retry(3, || {
+///     calls += 1;
+///     future::ready(if calls < 3 { Err(calls) } else { Ok(calls) })
+/// })
+/// .await;
+///
+/// assert_eq!(result, Ok(3));
+/// # })
+/// ```
+///
+/// Retrying a fallible signal future:
+///
+/// ```rust,ignore
+/// //in async
+/// future::retry(3, || button.pressed_fallible()).await
+/// ```
+pub fn retry<T, E, F, Fut>(attempts: u32, make_future: F) -> Retry<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    Retry::new(attempts, None, make_future)
+}
+
+/// Like [`retry()`], but waits between attempts as configured by `policy`.
+///
+/// The delays use [`wait_pooled()`], so they honour the pause state and `Engine.time_scale`.
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// let policy = Backoff::exponential(0.5, 2.0).max_delay(5.0).jitter(0.1);
+/// future::retry_with_backoff(policy, 5, || load_level()).await
+/// ```
+pub fn retry_with_backoff<T, E, F, Fut>(
+    policy: Backoff,
+    attempts: u32,
+    make_future: F,
+) -> Retry<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    Retry::new(attempts, Some(policy), make_future)
+}
+
+pin_project! {
+    /// Future for the [`retry()`] and [`retry_with_backoff()`] functions.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Retry<F, Fut> {
+        make_future: F,
+        attempts_left: u32,
+        retries: u32,
+        backoff: Option<Backoff>,
+        #[pin]
+        future: Option<Fut>,
+        #[pin]
+        delay: Option<Wait>,
+    }
+}
+
+impl<F, Fut> Retry<F, Fut> {
+    fn new(attempts: u32, backoff: Option<Backoff>, make_future: F) -> Self {
+        Retry {
+            make_future,
+            attempts_left: attempts.max(1),
+            retries: 0,
+            backoff,
+            future: None,
+            delay: None,
+        }
+    }
+}
+
+impl<T, E, F, Fut> Future for Retry<F, Fut>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                ready!(delay.poll(cx));
+                this.delay.set(None);
+            }
+
+            if this.future.is_none() {
+                this.future.set(Some((this.make_future)()));
+            }
+
+            let future = this.future.as_mut().as_pin_mut().expect("future is set");
+            match ready!(future.poll(cx)) {
+                Ok(t) => return Poll::Ready(Ok(t)),
+                Err(err) => {
+                    this.future.set(None);
+                    *this.attempts_left -= 1;
+                    if *this.attempts_left == 0 {
+                        return Poll::Ready(Err(err));
+                    }
+
+                    if let Some(backoff) = this.backoff {
+                        this.delay
+                            .set(Some(wait_pooled(backoff.jittered_delay(*this.retries))));
+                    }
+                    *this.retries += 1;
+                }
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// Future for the [`FutureExt::timed()`] and [`FutureExt::timed_with()`] methods.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Timed<F> {
+        #[pin]
+        inner: F,
+        stopwatch: Stopwatch,
+    }
+}
+
+impl<F: Future> Future for Timed<F> {
+    type Output = (F::Output, f64);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.inner.poll(cx));
+        Poll::Ready((output, this.stopwatch.elapsed()))
+    }
+}
+
 pin_project! {
     /// Future for the [`FutureExt::catch_unwind()`] method.
     #[derive(Debug)]
@@ -301,6 +552,67 @@ pub trait FutureExt: Future {
     {
         CatchUnwind { inner: self }
     }
+
+    /// Resolves to `Err(Cancelled)` if `token` is cancelled before `self` completes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use godot_await::cancel::{CancellationToken, Cancelled};
+    /// use godot_await::future::{pending, ready, FutureExt};
+    ///
+    /// # spin_on::spin_on(async {
+    /// let token = CancellationToken::new();
+    /// assert_eq!(ready(1).with_cancel(&token).await, Ok(1));
+    ///
+    /// token.cancel();
+    /// assert_eq!(pending::<i32>().with_cancel(&token).await, Err(Cancelled));
+    /// # })
+    /// ```
+    fn with_cancel(self, token: &CancellationToken) -> WithCancel<Self>
+    where
+        Self: Sized,
+    {
+        WithCancel::new(self, token)
+    }
+
+    /// Measures how much game time `self` took, resolving to `(output, elapsed_sec)`.
+    ///
+    /// The time is measured with [`Stopwatch::start()`] from the moment `timed` is called,
+    /// so it follows `Engine.time_scale` and pausing like [`wait()`](crate::wait::wait) does.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let ((), elapsed) = wait(1.0).timed().await;
+    /// ```
+    /// # Panics
+    ///
+    /// Will panic if the scene tree has no root. Without a `SceneTree`, this measures real time instead
+    fn timed(self) -> Timed<Self>
+    where
+        Self: Sized,
+    {
+        self.timed_with(Stopwatch::start())
+    }
+
+    /// Like [`timed`](Self::timed), measuring with `stopwatch`, e.g. an unscaled one.
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// //in async
+    /// let stopwatch = Stopwatch::start_ex().ignore_time_scale(true).done();
+    /// let (hit, real_time) = enemy.hit().timed_with(stopwatch).await;
+    /// ```
+    fn timed_with(self, stopwatch: Stopwatch) -> Timed<Self>
+    where
+        Self: Sized,
+    {
+        Timed {
+            inner: self,
+            stopwatch,
+        }
+    }
 }
 
 impl<F: Future + ?Sized> FutureExt for F {}
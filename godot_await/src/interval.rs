@@ -0,0 +1,245 @@
+//! Drift-free periodic ticks, on the same clocks as [`wait()`](crate::wait::wait).
+//!
+//! `loop { wait(0.2).await; tick(); }` drifts, since every wait starts after the previous wake-up and loses the rest of
+//! that frame. An [`Interval`] schedules every tick on a fixed grid (`start + n * period`) instead, and decides with its
+//! [`MissedTickBehavior`] what to do when the task fell behind by a whole period or more.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! //in async
+//! let mut interval = interval(0.2);
+//! loop {
+//!     interval.tick().await;
+//!     regenerate_health();
+//! }
+//! ```
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::future::Future;
+
+use crate::scheduler::{self, TimerMode};
+
+/// What an [`Interval`] does when ticks were missed, i.e. a tick is awaited a whole period or more after it was due.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Resolves the missed ticks right away, one after another, until it caught up with the original schedule.
+    #[default]
+    Burst,
+    /// Resolves one tick right away, and schedules the following ones a period after it.
+    Delay,
+    /// Resolves one tick right away, and continues with the next tick of the original schedule.
+    Skip,
+}
+
+/// Builder for creating configurable intervals, see [`interval_ex()`].
+#[derive(Debug, Clone, Copy)]
+pub struct ExIntervalBuilder {
+    period_sec: f64,
+    mode: TimerMode,
+    missed_tick_behavior: MissedTickBehavior,
+}
+
+impl ExIntervalBuilder {
+    /// Set whether the interval should ignore the time scale
+    ///
+    /// # Arguments
+    /// * `ignore_time_scale` - If true, the interval will ignore `Engine.time_scale` and tick with the real, elapsed time.
+    pub fn ignore_time_scale(mut self, ignore_time_scale: bool) -> Self {
+        self.mode.ignore_time_scale = ignore_time_scale;
+        self
+    }
+
+    /// Set whether the interval should always process
+    ///
+    /// # Arguments
+    /// * `process_always` - If is false, the interval will be paused when setting paused to true.
+    pub fn process_always(mut self, process_always: bool) -> Self {
+        self.mode.process_always = process_always;
+        self
+    }
+
+    /// Set whether the interval should process in physics
+    ///
+    /// # Arguments
+    /// * `process_in_physics` - If true, the interval will update at the end of the physics frame, instead of the process frame.
+    pub fn process_in_physics(mut self, process_in_physics: bool) -> Self {
+        self.mode.process_in_physics = process_in_physics;
+        self
+    }
+
+    /// Set what happens when ticks are missed, [`MissedTickBehavior::Burst`] by default.
+    pub fn missed_tick_behavior(mut self, behavior: MissedTickBehavior) -> Self {
+        self.missed_tick_behavior = behavior;
+        self
+    }
+
+    /// Finalize the interval. The first tick resolves right away.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the scene tree has no root. Without a `SceneTree`, the interval ticks in real time instead
+    #[inline]
+    pub fn done(self) -> Interval {
+        Interval {
+            period_sec: self.period_sec,
+            mode: self.mode,
+            missed_tick_behavior: self.missed_tick_behavior,
+            next: scheduler::elapsed(self.mode),
+            timer: None,
+        }
+    }
+}
+
+/// Ticks every `period_sec` seconds, see the [module docs](self).
+#[derive(Debug)]
+pub struct Interval {
+    period_sec: f64,
+    mode: TimerMode,
+    missed_tick_behavior: MissedTickBehavior,
+    /// Deadline of the next tick, on the clock of `mode`.
+    next: f64,
+    timer: Option<u64>,
+}
+
+impl Interval {
+    /// Returns a future resolving at the next tick.
+    ///
+    /// Dropping the future before it resolves does not consume the tick.
+    #[inline]
+    pub fn tick(&mut self) -> Tick<'_> {
+        Tick { interval: self }
+    }
+
+    /// Polls for the next tick, see [`tick`](Self::tick).
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(timer) = self.timer {
+            if scheduler::poll_timer(timer, cx.waker()).is_none() {
+                return Poll::Pending;
+            }
+            self.timer = None;
+        }
+
+        let now = scheduler::elapsed(self.mode);
+        if now < self.next {
+            let timer = scheduler::add_timer_at(self.next, self.mode);
+            // Registers the waker.
+            let _ = scheduler::poll_timer(timer, cx.waker());
+            self.timer = Some(timer);
+            return Poll::Pending;
+        }
+
+        self.next = if now < self.next + self.period_sec {
+            self.next + self.period_sec
+        } else {
+            match self.missed_tick_behavior {
+                MissedTickBehavior::Burst => self.next + self.period_sec,
+                MissedTickBehavior::Delay => now + self.period_sec,
+                MissedTickBehavior::Skip => {
+                    let missed = ((now - self.next) / self.period_sec).floor();
+                    self.next + (missed + 1.0) * self.period_sec
+                }
+            }
+        };
+        Poll::Ready(())
+    }
+
+    /// Restarts the schedule, with the next tick a period from now.
+    pub fn reset(&mut self) {
+        self.cancel_timer();
+        self.next = scheduler::elapsed(self.mode) + self.period_sec;
+    }
+
+    /// Period of the interval, in seconds.
+    #[inline]
+    pub fn period(&self) -> f64 {
+        self.period_sec
+    }
+
+    /// What happens when ticks are missed.
+    #[inline]
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Set what happens when ticks are missed, from the next tick on.
+    #[inline]
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = behavior;
+    }
+
+    fn cancel_timer(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            scheduler::remove_timer(timer);
+        }
+    }
+}
+
+impl Drop for Interval {
+    fn drop(&mut self) {
+        self.cancel_timer();
+    }
+}
+
+/// Future for [`Interval::tick`].
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Tick<'a> {
+    interval: &'a mut Interval,
+}
+
+impl Future for Tick<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.interval.poll_tick(cx)
+    }
+}
+
+/// Ticks every `period_sec` seconds, with the options of [`wait()`](crate::wait::wait).
+///
+/// # Arguments
+/// * `period_sec` - The time in seconds between two ticks
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// let mut interval = interval(0.2);
+/// interval.tick().await; // right away
+/// interval.tick().await; // 0.2s later
+/// ```
+/// # Panics
+///
+/// Will panic if `period_sec` is not positive, or if the scene tree has no root.
+/// Without a `SceneTree`, this ticks in real time instead
+#[inline]
+pub fn interval(period_sec: f64) -> Interval {
+    interval_ex(period_sec).done()
+}
+
+/// Create a configurable interval
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// let mut interval = interval_ex(0.2)
+///     .process_always(false)
+///     .missed_tick_behavior(MissedTickBehavior::Skip)
+///     .done();
+/// ```
+/// # Panics
+///
+/// Will panic if `period_sec` is not positive
+#[inline]
+pub fn interval_ex(period_sec: f64) -> ExIntervalBuilder {
+    assert!(
+        period_sec > 0.0,
+        "ERR(godot_await):interval period must be positive, got {period_sec}"
+    );
+    ExIntervalBuilder {
+        period_sec,
+        mode: TimerMode::default(),
+        missed_tick_behavior: MissedTickBehavior::default(),
+    }
+}
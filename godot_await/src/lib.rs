@@ -9,7 +9,7 @@
 //!
 //! Wait for a Duration
 //!
-//! ```rust
+//! ```rust,ignore
 //!   wait(1.0).await;
 //! ```
 //!
@@ -20,7 +20,7 @@
 //!   await get_tree().create_timer(1.0).timeout
 //! ```
 //!
-//! ```rust
+//! ```rust,ignore
 //!   // Rust (without godot_await)
 //!   let timer = Engine::singleton()
 //!       .get_main_loop().unwrap()
@@ -29,9 +29,17 @@
 //!   timer.signals().timeout().to_future().await;
 //! ```
 //!
+//! Many concurrent waits (e.g. cooldowns of hundreds of enemies) can share one pooled scheduler node instead of a
+//! `SceneTreeTimer` each, with the same pause and time-scale semantics:
+//!
+//! ```rust,ignore
+//!   wait_pooled(1.0).await;
+//!   wait_ex(&mut tree, 1.0).process_always(false).done_pooled().await;
+//! ```
+//!
 //! Wait for tween finished
 //!
-//! ```rust
+//! ```rust,ignore
 //!   tween.finished().await;
 //! ```
 //!
@@ -42,7 +50,7 @@
 //!   await tween.finished
 //! ```
 //!
-//! ```rust
+//! ```rust,ignore
 //!   // Rust (without godot_await)
 //!   tween.signals().finished().to_future().await;
 //! ```
@@ -53,7 +61,7 @@
 //!
 //!    >`FallibleSignalFuture`:The future might resolve to an error if the signal object is freed before the signal is emitted.[gdext repo](https://!github.com/godot-rust/gdext/blob/62a7381b3b297038b053ab63b1ad87997f6cc1e2/godot-core/src/task/futures.rs#L166)
 
-//! ```rust
+//! ```rust,ignore
 //!     task::spawn(async move {
 //!         let result = button.pressed_fallible().await;
 //!         assert!(result.is_err());
@@ -62,13 +70,36 @@
 //!     button.call_deferred("free", &[]);
 //! ```
 
+//! Testing without Godot
+//!
+//! Gameplay flows written against the `backend::Clock` and `backend::SignalSource` traits run in game with `GodotClock`
+//! and Ext trait closures, and in plain `cargo test` with the deterministic `mock` types
+//! (features `backend` and `mock`, the latter usually only in `[dev-dependencies]`).
+//!
+//! ```rust,ignore
+//!     async fn open_door(clock: impl Clock, pressed: impl SignalSource<Output = ()>) {
+//!         pressed.next().await;
+//!         clock.sleep(1.0).await;
+//!     }
+//!
+//!     // in game
+//!     open_door(GodotClock, || button.pressed()).await;
+//!
+//!     // in `cargo test`
+//!     let (clock, pressed) = (MockClock::new(), MockSignal::new());
+//!     let mut task = MockTask::new(open_door(clock.clone(), pressed.clone()));
+//!     assert_eq!(task.poll(), None);
+//!     pressed.emit(());
+//!     assert_eq!(task.advance(&clock, 1.0), Some(()));
+//! ```
+
 //! ## Crate Features
 
 //! `godot_await` has no features enabled by default.
 
 //! Optionally, the following dependencies can be enabled:
-//! - `future` enables `zip`,`or`,`try_zip`,`wait_or`, using the [pin-project-lite](https://crates.io/crates/pin-project-lite) crate.
-//! ```rust
+//! - `future` enables `zip`,`or`,`try_zip`,`wait_or`,`retry`,`retry_with_backoff`,`FutureExt::timed`, using the [pin-project-lite](https://crates.io/crates/pin-project-lite) crate.
+//! ```rust,ignore
 //!     //! Joins two futures, waiting for both to complete.
 //!     zip(tween.finished(),timer.timeout()).await;
 //!     //! Returns the result of the future that completes first.
@@ -76,26 +107,105 @@
 //!     //! Equivalent to
 //!     button.pressed().or(wait(1.0)).await;
 //! ```
+//!   It also enables the `cancel` module: `CancellationToken`, `FutureExt::with_cancel` and `abortable`.
+//! ```rust,ignore
+//!     let token = CancellationToken::new();
+//!     // Resolves to `Err(Cancelled)` once `token.cancel()` is called elsewhere.
+//!     let result = wait(5.0).with_cancel(&token.child_token()).await;
+//!     // Cancellation can race any signal future.
+//!     or(button.pressed(), token.cancelled()).await;
+//! ```
+//!   And the `sequence` module, with the `sequence!`/`parallel!` macros and the `Sequence` builder for cutscenes.
+//! ```rust,ignore
+//!     // Await one after another, then all at once.
+//!     sequence!(tween.finished(), wait(1.0)).await;
+//!     parallel!(sprite.animation_finished(), tween.finished(), wait(1.0)).await;
+//! ```
+//! - `diagnostics` warns (`godot_warn!`) when `TweenExt::finished`, `TimerExt::timeout` or `AnimatedSpriteExt::animation_finished`
+//!   is awaited on an object that can never emit it, e.g. a killed or infinitely looping Tween, a stopped Timer or a looping animation.
+//!   Their `_fallible` variants resolve to `Err` in that case, instead of never resolving.
+//! - `registry` records every outstanding await (object, class, signal, creation site, age) so stuck tasks can be listed.
+//! ```rust,ignore
+//!     task::spawn_named("door_open", async move { button.pressed().await });
+//!     // e.g. from a debug overlay
+//!     for pending in registry::pending_awaits() {
+//!         println!("{pending}"); // [door_open] Button#123.pressed at src/door.rs:12:16 (pending 3.52s)
+//!     }
+//! ```
+//! - `editor` (implies `registry`) adds an "Async Tasks" debugger tab to the editor, listing the pending awaits of the running game
+//!   (task name, awaited signal, owner node path, time pending). The game has to answer the editor's requests:
+//! ```rust,ignore
+//!     // e.g. in an autoload's `ready()`
+//!     godot_await::debugger::install();
+//! ```
+//! - `backend` enables the `backend` module: the `Clock` and `SignalSource` traits, with `GodotClock`.
+//! - `mock` (implies `backend`) enables the `mock` module: `MockClock`, `MockSignal` and `MockTask`.
 
+#[cfg(any(test, feature = "backend"))]
+pub mod backend;
+#[cfg(feature = "future")]
+pub mod cancel;
+#[cfg(feature = "editor")]
+pub mod debugger;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
 #[cfg(feature = "future")]
 pub mod future;
+pub mod interval;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 pub mod prelude;
+#[cfg(feature = "registry")]
+pub mod registry;
+mod scheduler;
+#[cfg(feature = "future")]
+pub mod sequence;
+pub mod stopwatch;
+pub mod task;
 mod utils;
 pub mod wait;
 
+/// Cargo features `godot_await` was compiled with, e.g. to label benchmark results.
+#[doc(hidden)]
+pub const ENABLED_FEATURES: &[&str] = &[
+    #[cfg(feature = "future")]
+    "future",
+    #[cfg(feature = "diagnostics")]
+    "diagnostics",
+    #[cfg(feature = "registry")]
+    "registry",
+    #[cfg(feature = "editor")]
+    "editor",
+    #[cfg(feature = "backend")]
+    "backend",
+    #[cfg(feature = "mock")]
+    "mock",
+];
+
 pub mod classes {
     pub mod animated_sprite;
     pub mod audio;
     pub mod base_button;
     pub mod canvas_item;
+    pub mod choose;
     pub mod control;
+    pub mod item_list;
+    pub mod line_edit;
+    pub mod navigation_agent;
     pub mod node;
+    pub mod option_button;
+    pub mod popup_menu;
+    pub mod range;
     pub mod scene_tree;
+    pub mod tab;
+    pub mod text_edit;
     pub mod timer;
+    pub mod tree;
     pub mod tween;
     pub mod viewport;
+    pub mod visibility;
+    pub mod window;
 }
 
-pub(crate) use godot::builtin::Signal;
 pub(crate) use godot::obj::{Gd, Inherits};
 pub(crate) use godot::task::{FallibleSignalFuture, SignalFuture};
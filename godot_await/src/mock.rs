@@ -0,0 +1,394 @@
+//! Deterministic in-memory implementations of [`Clock`] and [`SignalSource`], for testing without Godot.
+//!
+//! Nothing here calls into Godot, so flows written against the [backend](crate::backend) traits can be tested
+//! with plain `cargo test`: time only passes on [`MockClock::advance`], and signals only fire on [`MockSignal::emit`].
+//!
+//! # Examples
+//!
+//! ```
+//! use godot_await::backend::{Clock, SignalSource};
+//! use godot_await::mock::{MockClock, MockSignal, MockTask};
+//!
+//! async fn open_door(clock: impl Clock, pressed: impl SignalSource<Output = ()>) -> &'static str {
+//!     pressed.next().await;
+//!     clock.sleep(1.0).await;
+//!     "open"
+//! }
+//!
+//! let clock = MockClock::new();
+//! let pressed = MockSignal::new();
+//! let mut task = MockTask::new(open_door(clock.clone(), pressed.clone()));
+//!
+//! assert!(task.poll().is_none());
+//! pressed.emit(());
+//! assert_eq!(task.advance(&clock, 0.5), None);
+//! assert_eq!(task.advance(&clock, 0.5), Some("open"));
+//! ```
+
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::backend::{Clock, SignalSource};
+
+#[derive(Default)]
+struct ClockState {
+    now: f64,
+    sleepers: Vec<(f64, Waker)>,
+}
+
+/// A [`Clock`] which only moves on [`advance`](Self::advance). Clones share the same time.
+#[derive(Clone, Default)]
+pub struct MockClock {
+    state: Rc<RefCell<ClockState>>,
+}
+
+impl MockClock {
+    /// Creates a clock at time `0.0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the time forward by `time_sec` seconds, waking every sleep which is due.
+    pub fn advance(&self, time_sec: f64) {
+        let now = self.now() + time_sec.max(0.0);
+        self.set_now(now);
+    }
+
+    /// Returns the deadline of the earliest pending sleep.
+    pub fn next_deadline(&self) -> Option<f64> {
+        self.state
+            .borrow()
+            .sleepers
+            .iter()
+            .map(|(deadline, _)| *deadline)
+            .min_by(f64::total_cmp)
+    }
+
+    fn set_now(&self, now: f64) {
+        let due = {
+            let mut state = self.state.borrow_mut();
+            state.now = state.now.max(now);
+            let now = state.now;
+            let (due, pending) = state
+                .sleepers
+                .drain(..)
+                .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        for (_, waker) in due {
+            waker.wake();
+        }
+    }
+}
+
+impl Clock for MockClock {
+    type Sleep = MockSleep;
+
+    fn sleep(&self, time_sec: f64) -> Self::Sleep {
+        MockSleep {
+            clock: self.clone(),
+            deadline: self.now() + time_sec.max(0.0),
+        }
+    }
+
+    fn now(&self) -> f64 {
+        self.state.borrow().now
+    }
+}
+
+impl fmt::Debug for MockClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClock")
+            .field("now", &self.now())
+            .finish()
+    }
+}
+
+/// Future for the [`MockClock::sleep()`] method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MockSleep {
+    clock: MockClock,
+    deadline: f64,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.clock.state.borrow_mut();
+        if state.now >= self.deadline {
+            return Poll::Ready(());
+        }
+        let registered = state
+            .sleepers
+            .iter()
+            .any(|(deadline, waker)| *deadline == self.deadline && waker.will_wake(cx.waker()));
+        if !registered {
+            state.sleepers.push((self.deadline, cx.waker().clone()));
+        }
+        Poll::Pending
+    }
+}
+
+struct Listener<R> {
+    value: Option<R>,
+    waker: Option<Waker>,
+}
+
+struct SignalState<R> {
+    next_id: u64,
+    listeners: BTreeMap<u64, Listener<R>>,
+}
+
+/// A [`SignalSource`] which only fires on [`emit`](Self::emit). Clones share the same listeners.
+///
+/// Like a Godot signal future, each future created by [`next`](SignalSource::next) resolves to the first emission
+/// after its creation.
+pub struct MockSignal<R> {
+    state: Rc<RefCell<SignalState<R>>>,
+}
+
+impl<R> MockSignal<R> {
+    /// Creates a signal with no listeners.
+    pub fn new() -> Self {
+        MockSignal {
+            state: Rc::new(RefCell::new(SignalState {
+                next_id: 0,
+                listeners: BTreeMap::new(),
+            })),
+        }
+    }
+
+    /// Number of futures waiting for the next emission.
+    pub fn listener_count(&self) -> usize {
+        self.state
+            .borrow()
+            .listeners
+            .values()
+            .filter(|listener| listener.value.is_none())
+            .count()
+    }
+}
+
+impl<R: Clone> MockSignal<R> {
+    /// Emits the signal, resolving every waiting future with `args`.
+    pub fn emit(&self, args: R) {
+        let wakers = self
+            .state
+            .borrow_mut()
+            .listeners
+            .values_mut()
+            .filter(|listener| listener.value.is_none())
+            .filter_map(|listener| {
+                listener.value = Some(args.clone());
+                listener.waker.take()
+            })
+            .collect::<Vec<_>>();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Default for MockSignal<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for MockSignal<R> {
+    fn clone(&self) -> Self {
+        MockSignal {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<R> fmt::Debug for MockSignal<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockSignal")
+            .field("listener_count", &self.listener_count())
+            .finish()
+    }
+}
+
+impl<R> SignalSource for MockSignal<R> {
+    type Output = R;
+    type Future = MockSignalFuture<R>;
+
+    fn next(&self) -> Self::Future {
+        let id = {
+            let mut state = self.state.borrow_mut();
+            state.next_id += 1;
+            let id = state.next_id;
+            state.listeners.insert(
+                id,
+                Listener {
+                    value: None,
+                    waker: None,
+                },
+            );
+            id
+        };
+        MockSignalFuture {
+            signal: self.clone(),
+            id,
+        }
+    }
+}
+
+/// Future for the [`MockSignal::next()`](SignalSource::next) method.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MockSignalFuture<R> {
+    signal: MockSignal<R>,
+    id: u64,
+}
+
+impl<R> Future for MockSignalFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.signal.state.borrow_mut();
+        let listener = state
+            .listeners
+            .get_mut(&self.id)
+            .expect("ERR(godot_await):mock signal future polled after completion");
+        let Some(args) = listener.value.take() else {
+            listener.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        };
+        state.listeners.remove(&self.id);
+        Poll::Ready(args)
+    }
+}
+
+impl<R> Drop for MockSignalFuture<R> {
+    fn drop(&mut self) {
+        self.signal.state.borrow_mut().listeners.remove(&self.id);
+    }
+}
+
+impl<R> fmt::Debug for MockSignalFuture<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockSignalFuture")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+/// A future polled by hand, standing in for a spawned Godot task.
+#[must_use = "a task does nothing unless polled"]
+pub struct MockTask<T> {
+    future: Option<Pin<Box<dyn Future<Output = T>>>>,
+}
+
+impl<T> MockTask<T> {
+    /// Wraps `future` without polling it.
+    pub fn new(future: impl Future<Output = T> + 'static) -> Self {
+        MockTask {
+            future: Some(Box::pin(future)),
+        }
+    }
+
+    /// Polls the task once, returning its output if it completed.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the task already completed.
+    pub fn poll(&mut self) -> Option<T> {
+        let future = self
+            .future
+            .as_mut()
+            .expect("ERR(godot_await):mock task polled after completion");
+        let mut cx = Context::from_waker(Waker::noop());
+        let Poll::Ready(output) = future.as_mut().poll(&mut cx) else {
+            return None;
+        };
+        self.future = None;
+        Some(output)
+    }
+
+    /// Returns `true` once the task completed.
+    pub fn is_finished(&self) -> bool {
+        self.future.is_none()
+    }
+
+    /// Advances `clock` by `time_sec` seconds, stopping at every deadline on the way to poll the task,
+    /// so chained sleeps resolve at the same simulated time as in game.
+    ///
+    /// # Returns
+    /// The output of the task if it completed during the advance, in which case the clock stops at that time.
+    pub fn advance(&mut self, clock: &MockClock, time_sec: f64) -> Option<T> {
+        let target = clock.now() + time_sec.max(0.0);
+        loop {
+            if let Some(output) = self.poll() {
+                return Some(output);
+            }
+            match clock.next_deadline() {
+                Some(deadline) if deadline <= target => clock.set_now(deadline),
+                _ => break,
+            }
+        }
+        clock.set_now(target);
+        self.poll()
+    }
+}
+
+impl<T> fmt::Debug for MockTask<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTask")
+            .field("is_finished", &self.is_finished())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn toggle_after(
+        clock: impl Clock,
+        toggled: impl SignalSource<Output = (bool,)>,
+        delay: f64,
+    ) -> (bool, f64) {
+        let (on,) = toggled.next().await;
+        let start = clock.now();
+        clock.sleep(delay).await;
+        (on, clock.now() - start)
+    }
+
+    #[test]
+    fn mock_task() {
+        let clock = MockClock::new();
+        let toggled = MockSignal::new();
+        let mut task = MockTask::new(toggle_after(clock.clone(), toggled.clone(), 1.0));
+        assert_eq!(task.poll(), None);
+        assert_eq!(toggled.listener_count(), 1);
+        toggled.emit((true,));
+        assert_eq!(toggled.listener_count(), 0);
+        assert_eq!(task.poll(), None);
+        assert_eq!(clock.next_deadline(), Some(1.0));
+        assert_eq!(task.advance(&clock, 0.5), None);
+        assert_eq!(task.advance(&clock, 2.0), Some((true, 1.0)));
+        assert!(task.is_finished());
+        // The clock stops when the task completes.
+        assert_eq!(clock.now(), 1.0);
+    }
+
+    #[test]
+    fn dropped_listener() {
+        let toggled = MockSignal::<(bool,)>::new();
+        let mut task = MockTask::new(toggled.next());
+        assert_eq!(task.poll(), None);
+        assert_eq!(toggled.listener_count(), 1);
+        drop(task);
+        assert_eq!(toggled.listener_count(), 0);
+    }
+}
@@ -1,4 +1,7 @@
-//! Traits `BaseButtonExt`, `NodeExt`, `SceneTreeExt`, `TimerExt`, `TweenExt`.
+//! Traits `AudioServerExt`, `AudioStreamPlayerExt`, `BaseButtonExt`, `CodeEditExt`, `ItemListExt`, `LineEditExt`,
+//! `MenuButtonExt`, `NavigationAgentExt`, `NodeExt`, `OptionButtonExt`, `PopupMenuExt`, `RangeExt`, `SceneTreeExt`,
+//! `SliderExt`, `TabBarExt`, `TabContainerExt`, `TextEditExt`, `TimerExt`, `TreeExt`, `TweenExt`, `VisibleOnScreenExt`,
+//! `WindowExt`.
 //!
 //! # Examples
 //!
@@ -6,11 +9,25 @@
 //! use godot_await::prelude::*;
 //! ```
 
+#[cfg(feature = "future")]
+pub use crate::cancel::CancellationToken;
 #[doc(no_inline)]
 pub use crate::classes::{
-    audio::AudioStreamPlayerExt as _, base_button::BaseButtonExt as _, node::NodeExt as _,
-    scene_tree::SceneTreeExt as _, timer::TimerExt as _, tween::TweenExt as _,
+    audio::AudioServerExt as _, audio::AudioStreamPlayerExt as _, base_button::BaseButtonExt as _,
+    item_list::ItemListExt as _, line_edit::LineEditExt as _,
+    navigation_agent::NavigationAgentExt as _, node::NodeExt as _,
+    option_button::OptionButtonExt as _, popup_menu::MenuButtonExt as _,
+    popup_menu::PopupMenuExt as _, range::RangeExt as _, range::SliderExt as _,
+    scene_tree::SceneTreeExt as _, tab::TabBarExt as _, tab::TabContainerExt as _,
+    text_edit::CodeEditExt as _, text_edit::TextEditExt as _, timer::TimerExt as _,
+    tree::TreeExt as _, tween::TweenExt as _, visibility::VisibleOnScreenExt as _,
+    window::WindowExt as _,
 };
 #[cfg(feature = "future")]
 pub use crate::future::{Future, FutureExt as _};
-pub use crate::wait::{wait, wait_ex};
+pub use crate::interval::interval;
+#[cfg(feature = "future")]
+pub use crate::sequence::Sequence;
+pub use crate::stopwatch::Stopwatch;
+pub use crate::task::spawn_named;
+pub use crate::wait::{wait, wait_ex, wait_pooled, wait_realtime};
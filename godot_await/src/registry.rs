@@ -0,0 +1,262 @@
+//! Registry of pending awaits, enabled by the `registry` feature.
+//!
+//! Every signal future created through this crate is recorded with the awaited object, signal, creation site and
+//! the name of the task it was created in (see [`spawn_named`](crate::task::spawn_named)).
+//! An entry is removed when the signal is emitted, when the future is dropped (e.g. the losing side of `or`),
+//! when the object is freed, or when its named task ends.
+//! Waits are listed as `WaitScheduler#<id>.wait(<time>s)`, and removed as soon as they resolve or are dropped.
+//!
+//! The registry adds no connection of its own: an entry remembers the connection its signal future made on creation,
+//! which gdext removes once the signal is emitted or the future is dropped, and is discarded as soon as that connection
+//! is gone.
+
+use core::fmt;
+use core::fmt::Write;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::panic::Location;
+use std::time::{Duration, Instant};
+
+use godot::builtin::Callable;
+use godot::classes::Object;
+use godot::global::godot_print;
+use godot::obj::{Gd, InstanceId};
+
+use crate::utils::last_connection;
+
+/// An outstanding await, as recorded by the registry.
+#[derive(Debug, Clone)]
+pub struct PendingAwait {
+    /// Name of the task the future was created in, if spawned with `spawn_named`.
+    pub task: Option<String>,
+    /// The object emitting the awaited signal.
+    pub object_id: InstanceId,
+    /// Class name of the object.
+    pub class: String,
+    /// Name of the awaited signal.
+    pub signal: String,
+    /// Where the future was created.
+    pub location: &'static Location<'static>,
+    /// When the future was created.
+    pub created: Instant,
+}
+
+impl PendingAwait {
+    /// How long this await has been pending.
+    pub fn age(&self) -> Duration {
+        self.created.elapsed()
+    }
+}
+
+impl fmt::Display for PendingAwait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let task = self.task.as_deref().unwrap_or("<unnamed>");
+        write!(
+            f,
+            "[{task}] {}#{}.{} at {} (pending {:.2}s)",
+            self.class,
+            self.object_id,
+            self.signal,
+            self.location,
+            self.age().as_secs_f64()
+        )
+    }
+}
+
+struct Entry {
+    task: Option<u64>,
+    pending: PendingAwait,
+    /// Hash of the connection of the signal future, `None` for awaits removed with [`untrack`].
+    ///
+    /// Not the `Callable` itself: holding it would keep a fallible future from noticing that its object was freed.
+    connection: Option<u32>,
+}
+
+impl Entry {
+    fn is_pending(&self) -> bool {
+        let Ok(object) = Gd::<Object>::try_from_instance_id(self.pending.object_id) else {
+            return false;
+        };
+        match self.connection {
+            Some(connection) => object
+                .get_signal_connection_list(self.pending.signal.as_str())
+                .iter_shared()
+                .filter_map(|info| info.get("callable")?.try_to::<Callable>().ok())
+                .any(|callable| callable.hash_u32() == connection),
+            None => true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    next_id: u64,
+    entries: BTreeMap<u64, Entry>,
+    tasks: HashMap<u64, String>,
+    current_task: Option<u64>,
+    /// Number of entries after the last pruning.
+    pruned_len: usize,
+}
+
+impl Registry {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn insert(
+        &mut self,
+        object_id: InstanceId,
+        class: String,
+        signal: String,
+        location: &'static Location<'static>,
+        connection: Option<u32>,
+    ) -> u64 {
+        let id = self.next_id();
+        let task = self.current_task;
+        let pending = PendingAwait {
+            task: task.and_then(|task| self.tasks.get(&task).cloned()),
+            object_id,
+            class,
+            signal,
+            location,
+            created: Instant::now(),
+        };
+        self.entries.insert(
+            id,
+            Entry {
+                task,
+                pending,
+                connection,
+            },
+        );
+        id
+    }
+
+    fn prune(&mut self) {
+        self.entries.retain(|_, entry| entry.is_pending());
+        self.pruned_len = self.entries.len();
+    }
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+/// Returns all outstanding awaits, oldest first.
+pub fn pending_awaits() -> Vec<PendingAwait> {
+    REGISTRY.with_borrow_mut(|registry| {
+        registry.prune();
+        registry
+            .entries
+            .values()
+            .map(|entry| entry.pending.clone())
+            .collect()
+    })
+}
+
+/// Formats all outstanding awaits, one per line.
+pub fn dump_pending() -> String {
+    let mut dump = String::new();
+    for pending in pending_awaits() {
+        let _ = writeln!(dump, "{pending}");
+    }
+    dump
+}
+
+/// Prints all outstanding awaits to the Godot output.
+pub fn print_pending() {
+    let pending = pending_awaits();
+    godot_print!("godot_await: {} pending await(s)", pending.len());
+    for pending in pending {
+        godot_print!("  {pending}");
+    }
+}
+
+/// Removes an await recorded with [`track`].
+pub(crate) fn untrack(id: u64) {
+    // Ignore errors during thread-local destruction.
+    let _ = REGISTRY.try_with(|registry| registry.borrow_mut().entries.remove(&id));
+}
+
+/// Records an await which is not tied to a signal connection; it has to be removed with [`untrack`].
+#[track_caller]
+pub(crate) fn track(object_id: InstanceId, class: String, signal: String) -> u64 {
+    let location = Location::caller();
+    REGISTRY.with_borrow_mut(|registry| registry.insert(object_id, class, signal, location, None))
+}
+
+/// Records the signal future which was just connected to `signal` of `object`, see `utils::ObjectSignal`.
+pub(crate) fn track_signal(
+    object_id: InstanceId,
+    signal: &'static str,
+    location: &'static Location<'static>,
+) {
+    let Ok(object) = Gd::<Object>::try_from_instance_id(object_id) else {
+        return;
+    };
+    let Some(connection) = last_connection(&object, signal) else {
+        return;
+    };
+
+    REGISTRY.with_borrow_mut(|registry| {
+        // Amortized, so entries of dropped futures cannot pile up between two listings.
+        if registry.entries.len() >= (2 * registry.pruned_len).max(64) {
+            registry.prune();
+        }
+        registry.insert(
+            object_id,
+            object.get_class().to_string(),
+            signal.to_string(),
+            location,
+            Some(connection.hash_u32()),
+        );
+    });
+}
+
+/// Future spawned by `spawn_named`, attributing the awaits created while polling it to its task.
+pub(crate) struct Named {
+    task: u64,
+    inner: Pin<Box<dyn Future<Output = ()>>>,
+}
+
+impl Named {
+    pub(crate) fn new(name: String, inner: impl Future<Output = ()> + 'static) -> Self {
+        let task = REGISTRY.with_borrow_mut(|registry| {
+            let task = registry.next_id();
+            registry.tasks.insert(task, name);
+            task
+        });
+        Named {
+            task,
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+impl Future for Named {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let task = self.task;
+        let outer = REGISTRY.with_borrow_mut(|registry| registry.current_task.replace(task));
+        let poll = self.inner.as_mut().poll(cx);
+        REGISTRY.with_borrow_mut(|registry| registry.current_task = outer);
+        poll
+    }
+}
+
+impl Drop for Named {
+    fn drop(&mut self) {
+        let task = self.task;
+        // Ignore errors during thread-local destruction.
+        let _ = REGISTRY.try_with(|registry| {
+            let mut registry = registry.borrow_mut();
+            registry.tasks.remove(&task);
+            registry.entries.retain(|_, entry| entry.task != Some(task));
+        });
+    }
+}
@@ -0,0 +1,480 @@
+//! Pooled timers behind [`wait_pooled()`](crate::wait::wait_pooled).
+//!
+//! Instead of one `SceneTreeTimer` per wait, a single driver node keeps a min-heap of deadlines per timer mode and wakes
+//! the waiting futures directly. The driver processes last (`process_priority = i32::MAX`) and always, and advances the
+//! timers in a deferred call: it runs after the tasks resumed by this frame's node processing were polled, and before the
+//! tree processes its own timers. So timers advance in the same frames as a `SceneTreeTimer` created at the same point
+//! would, and pausing is applied per mode. The driver is a tool class, so waits also resolve in editor plugins.
+//!
+//! Node-bound waits count down only in frames where their node can process (see `Node.can_process()`), so they follow
+//! the node's effective `process_mode`, and resolve as [`Fired::LeftTree`] once the node is no longer inside the tree.
+//!
+//! Without a `SceneTree`, waits fall back to real time: deadlines on `Time.get_ticks_usec()`, checked by [`tick_realtime`]
+//! after every drawn frame and whenever the user calls it (e.g. from a custom `MainLoop`). Every timer mode then reads
+//! that same real-time clock, so stopwatches and intervals keep working too. [`wait()`](crate::wait::wait) returns a
+//! signal future, so its real-time timers emit the `timeout` signal of a [`RealtimeTimer`] instead of waking a future.
+
+use core::cmp::{Ordering, Reverse};
+use core::task::Waker;
+use std::cell::RefCell;
+use std::collections::{BinaryHeap, HashMap};
+
+use godot::builtin::{Callable, Variant};
+use godot::classes::node::ProcessMode;
+use godot::classes::{Engine, INode, Node, Object, RefCounted, RenderingServer, SceneTree, Time};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, InstanceId, NewAlloc, NewGd};
+use godot::register::{GodotClass, godot_api};
+
+use crate::utils::{godot_tree, try_godot_tree};
+
+/// How a timer advances, matching the `SceneTree.create_timer()` arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TimerMode {
+    pub(crate) process_always: bool,
+    pub(crate) process_in_physics: bool,
+    pub(crate) ignore_time_scale: bool,
+}
+
+impl TimerMode {
+    const COUNT: usize = 8;
+
+    fn index(self) -> usize {
+        (self.process_in_physics as usize) << 2
+            | (self.process_always as usize) << 1
+            | self.ignore_time_scale as usize
+    }
+}
+
+impl Default for TimerMode {
+    fn default() -> Self {
+        TimerMode {
+            process_always: true,
+            process_in_physics: false,
+            ignore_time_scale: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Deadline {
+    at: f64,
+    id: u64,
+}
+
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Deadline {}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Deadline {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Timers with the same deadline fire in creation order.
+        self.at.total_cmp(&other.at).then(self.id.cmp(&other.id))
+    }
+}
+
+/// Time of one timer mode, and the deadlines measured against it.
+#[derive(Default)]
+struct TimerClock {
+    elapsed: f64,
+    deadlines: BinaryHeap<Reverse<Deadline>>,
+}
+
+/// How a timer resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Fired {
+    TimedOut,
+    LeftTree,
+}
+
+#[derive(Default)]
+struct Slot {
+    fired: Option<Fired>,
+    waker: Option<Waker>,
+}
+
+/// A timer bound to a node, counting down with its own time left.
+struct NodeTimer {
+    id: u64,
+    node: InstanceId,
+    time_left: f64,
+    process_in_physics: bool,
+    ignore_time_scale: bool,
+}
+
+#[derive(Default)]
+struct Scheduler {
+    driver: Option<InstanceId>,
+    next_id: u64,
+    clocks: [TimerClock; TimerMode::COUNT],
+    slots: HashMap<u64, Slot>,
+    last_process_usec: Option<u64>,
+    realtime: BinaryHeap<Reverse<Deadline>>,
+    realtime_connected: bool,
+    /// Real-time timers of `wait()`, emitting a signal instead of waking a future.
+    realtime_emitters: HashMap<u64, Gd<RealtimeTimer>>,
+    node_timers: Vec<NodeTimer>,
+}
+
+thread_local! {
+    static SCHEDULER: RefCell<Scheduler> = RefCell::default();
+}
+
+impl Scheduler {
+    fn insert_slot(&mut self) -> u64 {
+        self.next_id += 1;
+        self.slots.insert(self.next_id, Slot::default());
+        self.next_id
+    }
+}
+
+/// Adds a timer of `time_sec` seconds, returning its ID and the instance ID of the driver.
+pub(crate) fn add_timer(tree: &SceneTree, time_sec: f64, mode: TimerMode) -> (u64, InstanceId) {
+    let driver = driver(tree);
+    let id = SCHEDULER.with_borrow_mut(|scheduler| {
+        let id = scheduler.insert_slot();
+        let clock = &mut scheduler.clocks[mode.index()];
+        clock.deadlines.push(Reverse(Deadline {
+            at: clock.elapsed + time_sec,
+            id,
+        }));
+        id
+    });
+    (id, driver)
+}
+
+/// Adds a timer firing once the clock of `mode` reaches `at` (see [`elapsed`]).
+pub(crate) fn add_timer_at(at: f64, mode: TimerMode) -> u64 {
+    let Some(tree) = try_godot_tree() else {
+        return add_realtime_timer_at(at);
+    };
+    driver(&tree);
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let id = scheduler.insert_slot();
+        scheduler.clocks[mode.index()]
+            .deadlines
+            .push(Reverse(Deadline { at, id }));
+        id
+    })
+}
+
+/// Time the clock of `mode` advanced since the first wait or stopwatch, in seconds.
+///
+/// Without a `SceneTree`, this is the real time since the engine started instead.
+pub(crate) fn elapsed(mode: TimerMode) -> f64 {
+    let Some(tree) = try_godot_tree() else {
+        return now_sec();
+    };
+    driver(&tree);
+    SCHEDULER.with_borrow(|scheduler| scheduler.clocks[mode.index()].elapsed)
+}
+
+/// Adds a timer of `time_sec` seconds bound to `node`. The node has to be inside the tree.
+pub(crate) fn add_node_timer(
+    node: &Gd<Node>,
+    time_sec: f64,
+    process_in_physics: bool,
+    ignore_time_scale: bool,
+) -> u64 {
+    if let Some(tree) = try_godot_tree() {
+        driver(&tree);
+    }
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let id = scheduler.insert_slot();
+        scheduler.node_timers.push(NodeTimer {
+            id,
+            node: node.instance_id(),
+            time_left: time_sec,
+            process_in_physics,
+            ignore_time_scale,
+        });
+        id
+    })
+}
+
+/// Adds a timer which already resolved as `fired`.
+pub(crate) fn add_fired_timer(fired: Fired) -> u64 {
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let id = scheduler.insert_slot();
+        scheduler
+            .slots
+            .get_mut(&id)
+            .expect("slot was inserted")
+            .fired = Some(fired);
+        id
+    })
+}
+
+/// Adds a timer of `time_sec` seconds of real time, which ignores pausing and `Engine.time_scale`.
+pub(crate) fn add_realtime_timer(time_sec: f64) -> u64 {
+    add_realtime_timer_at(now_sec() + time_sec)
+}
+
+fn add_realtime_timer_at(at: f64) -> u64 {
+    let (id, connect) = SCHEDULER.with_borrow_mut(|scheduler| {
+        let id = scheduler.insert_slot();
+        scheduler.realtime.push(Reverse(Deadline { at, id }));
+        (
+            id,
+            !std::mem::replace(&mut scheduler.realtime_connected, true),
+        )
+    });
+
+    if connect {
+        let callable = Callable::from_local_fn("godot_await::tick_realtime", |_| {
+            tick_realtime();
+            Ok(Variant::nil())
+        });
+        RenderingServer::singleton().connect("frame_post_draw", &callable);
+    }
+    id
+}
+
+/// Adds a timer of `time_sec` seconds of real time, which emits `timeout` on the returned object once it fires.
+pub(crate) fn add_realtime_emitter(time_sec: f64) -> Gd<RealtimeTimer> {
+    let emitter = RealtimeTimer::new_gd();
+    let id = add_realtime_timer(time_sec);
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        scheduler.realtime_emitters.insert(id, emitter.clone());
+    });
+    emitter
+}
+
+/// Resolves the real-time waits whose deadline has passed.
+pub(crate) fn tick_realtime() {
+    let now = now_sec();
+    let mut wakers = Vec::new();
+    let mut emitters = Vec::new();
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let Scheduler {
+            realtime,
+            slots,
+            realtime_emitters,
+            ..
+        } = scheduler;
+        fire_due(realtime, now, slots, &mut wakers);
+        realtime_emitters.retain(|id, emitter| {
+            if slots.get(id).is_some_and(|slot| slot.fired.is_some()) {
+                slots.remove(id);
+                emitters.push(emitter.clone());
+                false
+            } else {
+                true
+            }
+        });
+    });
+    wake_all(wakers);
+    for emitter in emitters {
+        emitter.upcast::<Object>().emit_signal("timeout", &[]);
+    }
+}
+
+fn now_sec() -> f64 {
+    Time::singleton().get_ticks_usec() as f64 / 1_000_000.0
+}
+
+fn fire_due(
+    deadlines: &mut BinaryHeap<Reverse<Deadline>>,
+    now: f64,
+    slots: &mut HashMap<u64, Slot>,
+    wakers: &mut Vec<Waker>,
+) {
+    while let Some(Reverse(deadline)) = deadlines.peek() {
+        if deadline.at > now {
+            break;
+        }
+        let id = deadline.id;
+        deadlines.pop();
+        fire(slots, id, Fired::TimedOut, wakers);
+    }
+}
+
+fn fire(slots: &mut HashMap<u64, Slot>, id: u64, fired: Fired, wakers: &mut Vec<Waker>) {
+    if let Some(slot) = slots.get_mut(&id) {
+        slot.fired = Some(fired);
+        wakers.extend(slot.waker.take());
+    }
+}
+
+/// Wakes outside of the scheduler borrow, in case an executor polls right away.
+fn wake_all(wakers: Vec<Waker>) {
+    for waker in wakers {
+        waker.wake();
+    }
+}
+
+/// Returns how the timer resolved, registering `waker` to be woken when it does otherwise.
+pub(crate) fn poll_timer(id: u64, waker: &Waker) -> Option<Fired> {
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let slot = scheduler.slots.get_mut(&id)?;
+        if let Some(fired) = slot.fired {
+            scheduler.slots.remove(&id);
+            return Some(fired);
+        }
+        match &mut slot.waker {
+            Some(current) if current.will_wake(waker) => {}
+            current => *current = Some(waker.clone()),
+        }
+        None
+    })
+}
+
+/// Removes a timer; its deadline is discarded once it comes up.
+pub(crate) fn remove_timer(id: u64) {
+    // Ignore errors during thread-local destruction.
+    let _ = SCHEDULER.try_with(|scheduler| scheduler.borrow_mut().slots.remove(&id));
+}
+
+fn driver(tree: &SceneTree) -> InstanceId {
+    let current = SCHEDULER.with_borrow(|scheduler| scheduler.driver);
+    if let Some(id) = current {
+        if Gd::<Node>::try_from_instance_id(id).is_ok() {
+            return id;
+        }
+    }
+
+    let mut driver = WaitScheduler::new_alloc();
+    driver.set_name("GodotAwaitScheduler");
+    driver.set_process_mode(ProcessMode::ALWAYS);
+    driver.set_process_priority(i32::MAX);
+    driver.set_physics_process_priority(i32::MAX);
+    // Deferred, since the root may be busy setting up its children (e.g. when awaiting in `ready()`).
+    let mut root = tree
+        .get_root()
+        .expect("ERR(godot_await):get scene tree root failed");
+    root.call_deferred("add_child", &[driver.to_variant()]);
+
+    // Timers of a freed driver keep their deadlines and continue on the new one.
+    let id = driver.instance_id();
+    SCHEDULER.with_borrow_mut(|scheduler| scheduler.driver = Some(id));
+    id
+}
+
+fn advance(process_in_physics: bool, delta: f64, paused: bool) {
+    let time_scale = Engine::singleton().get_time_scale();
+    let unscaled_delta = if process_in_physics {
+        1.0 / Engine::singleton().get_physics_ticks_per_second() as f64
+    } else {
+        let now = Time::singleton().get_ticks_usec();
+        let last = SCHEDULER.with_borrow_mut(|scheduler| scheduler.last_process_usec.replace(now));
+        if time_scale > 0.0 {
+            delta / time_scale
+        } else {
+            // The scaled delta is 0, so fall back to the real time since the last frame.
+            last.map_or(0.0, |last| now.saturating_sub(last) as f64 / 1_000_000.0)
+        }
+    };
+
+    let mut wakers = Vec::new();
+    SCHEDULER.with_borrow_mut(|scheduler| {
+        let Scheduler {
+            clocks,
+            slots,
+            node_timers,
+            ..
+        } = scheduler;
+        for process_always in [false, true] {
+            if paused && !process_always {
+                continue;
+            }
+            for ignore_time_scale in [false, true] {
+                let mode = TimerMode {
+                    process_always,
+                    process_in_physics,
+                    ignore_time_scale,
+                };
+                let clock = &mut clocks[mode.index()];
+                clock.elapsed += if ignore_time_scale {
+                    unscaled_delta
+                } else {
+                    delta
+                };
+                fire_due(&mut clock.deadlines, clock.elapsed, slots, &mut wakers);
+            }
+        }
+
+        node_timers.retain_mut(|timer| {
+            if !slots.contains_key(&timer.id) {
+                // Dropped.
+                return false;
+            }
+            if timer.process_in_physics != process_in_physics {
+                return true;
+            }
+
+            let fired = match Gd::<Node>::try_from_instance_id(timer.node) {
+                Ok(node) if node.is_inside_tree() => {
+                    if node.can_process() {
+                        timer.time_left -= if timer.ignore_time_scale {
+                            unscaled_delta
+                        } else {
+                            delta
+                        };
+                    }
+                    (timer.time_left <= 0.0).then_some(Fired::TimedOut)
+                }
+                _ => Some(Fired::LeftTree),
+            };
+            match fired {
+                Some(fired) => {
+                    fire(slots, timer.id, fired, &mut wakers);
+                    false
+                }
+                None => true,
+            }
+        });
+    });
+    wake_all(wakers);
+}
+
+/// Node driving the pooled timers, added to the root on the first pooled wait, stopwatch or interval.
+#[derive(GodotClass)]
+#[class(init, internal, tool, base=Node)]
+pub(crate) struct WaitScheduler {
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl WaitScheduler {
+    #[func]
+    fn advance_timers(&mut self, process_in_physics: bool, delta: f64) {
+        advance(process_in_physics, delta, godot_tree().is_paused());
+    }
+}
+
+#[godot_api]
+impl INode for WaitScheduler {
+    fn process(&mut self, delta: f64) {
+        // Deferred, so that waits created by tasks resumed during node processing still count this frame.
+        self.base_mut()
+            .call_deferred("advance_timers", &[false.to_variant(), delta.to_variant()]);
+    }
+
+    fn physics_process(&mut self, delta: f64) {
+        self.base_mut()
+            .call_deferred("advance_timers", &[true.to_variant(), delta.to_variant()]);
+    }
+}
+
+/// Stands in for the `SceneTreeTimer` of `wait()` without a `SceneTree`, see [`add_realtime_emitter`].
+#[derive(GodotClass)]
+#[class(init, internal, tool, base=RefCounted)]
+pub(crate) struct RealtimeTimer {
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl RealtimeTimer {
+    #[signal]
+    fn timeout();
+}
@@ -0,0 +1,252 @@
+//! Composing scripted sequences, e.g. for cutscenes.
+//!
+//! - [`sequence!`](crate::sequence!) awaits futures one after another.
+//! - [`parallel!`](crate::parallel!) awaits futures concurrently.
+//! - [`Sequence`] runs labeled steps, which can be skipped to the end or fast-forwarded in tests.
+
+use core::future::poll_fn;
+use core::pin::Pin;
+use core::task::Poll;
+use std::cell::Cell;
+
+use crate::cancel::CancellationToken;
+use crate::future::{Future, FutureExt};
+
+/// Awaits the given futures one after another, resolving to a tuple of their outputs.
+///
+/// Each future expression is only evaluated once the previous one has completed,
+/// so timers like `wait(1.0)` start when their step is reached.
+///
+/// # Examples
+///
+/// ```
+/// use godot_await::sequence;
+/// use godot_await::future::ready;
+///
+/// # spin_on::spin_on(async {
+/// assert_eq!(sequence!(ready(1), ready("two")).await, (1, "two"));
+/// # })
+/// ```
+///
+/// ```rust,ignore
+/// //in async
+/// sequence!(
+///     tween.finished(),
+///     sprite.animation_finished(),
+///     wait(1.0),
+/// )
+/// .await;
+/// ```
+#[macro_export]
+macro_rules! sequence {
+    ($($fut:expr),+ $(,)?) => {
+        async { ($($fut.await,)+) }
+    };
+}
+
+/// Awaits the given futures concurrently, resolving to a tuple of their outputs.
+///
+/// Equivalent to nested [`zip()`](crate::future::zip) calls, with the output flattened. Up to 12 futures are supported.
+///
+/// # Examples
+///
+/// ```
+/// use godot_await::parallel;
+/// use godot_await::future::ready;
+///
+/// # spin_on::spin_on(async {
+/// assert_eq!(parallel!(ready(1), ready(2), ready(3)).await, (1, 2, 3));
+/// # })
+/// ```
+#[macro_export]
+macro_rules! parallel {
+    ($($fut:expr),+ $(,)?) => {
+        $crate::__parallel!([] [_0 _1 _2 _3 _4 _5 _6 _7 _8 _9 _10 _11] $($fut,)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parallel {
+    // All futures are named: zip them and flatten the nested output.
+    ([$($name:ident $fut:expr,)+] [$($unused:ident)*]) => {
+        async {
+            let $crate::__parallel!(@pat $($name)+) = $crate::__parallel!(@zip $($fut,)+).await;
+            ($($name,)+)
+        }
+    };
+    // Assign the next free name to the next future.
+    ([$($done:tt)*] [$name:ident $($names:ident)*] $fut:expr, $($rest:expr,)*) => {
+        $crate::__parallel!([$($done)* $name $fut,] [$($names)*] $($rest,)*)
+    };
+    (@pat $name:ident) => { $name };
+    (@pat $name:ident $($rest:ident)+) => { ($name, $crate::__parallel!(@pat $($rest)+)) };
+    (@zip $fut:expr,) => { $fut };
+    (@zip $fut:expr, $($rest:expr,)+) => {
+        $crate::future::zip($fut, $crate::__parallel!(@zip $($rest,)+))
+    };
+}
+
+thread_local! {
+    static FAST_FORWARD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Fast-forwards every [`Sequence`] run on this thread, as if [`Sequence::fast_forward`] was enabled.
+///
+/// Intended for tests, which can then exercise gameplay sequences without waiting for them.
+pub fn set_fast_forward(enabled: bool) {
+    FAST_FORWARD.set(enabled);
+}
+
+type StartFn = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()>>>>;
+
+struct Step {
+    label: String,
+    start: StartFn,
+    on_skip: Option<Box<dyn FnOnce()>>,
+}
+
+/// Labels of the steps of a [`Sequence`], by how they ended.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SequenceReport {
+    /// Steps whose future completed.
+    pub completed: Vec<String>,
+    /// Steps which were skipped or fast-forwarded.
+    pub skipped: Vec<String>,
+}
+
+impl SequenceReport {
+    fn skip(&mut self, label: String, on_skip: Option<Box<dyn FnOnce()>>) {
+        if let Some(on_skip) = on_skip {
+            on_skip();
+        }
+        self.skipped.push(label);
+    }
+}
+
+/// Builder for a sequence of labeled steps.
+///
+/// Each step is a closure creating its future, called once the previous step has completed.
+///
+/// - When the [`skip_on`](Self::skip_on) token is cancelled, the current step is dropped and the remaining
+///   steps are not started. The `on_skip` hook of every skipped step runs instead, so it can jump to its end state.
+/// - When [fast-forwarded](Self::fast_forward), every step is started and polled once; if it did not complete
+///   right away, it is skipped.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// //in async
+/// let skip = CancellationToken::new();
+/// let report = Sequence::new()
+///     .step("camera", move || tween.finished())
+///     .on_skip(move || tween_ref.custom_step(f64::INFINITY))
+///     .step("dialog", move || dialog.pressed())
+///     .step("pause", || wait(1.0))
+///     .skip_on(&skip)
+///     .run()
+///     .await;
+/// ```
+#[derive(Default)]
+#[must_use = "a sequence does nothing unless you `.run().await` it"]
+pub struct Sequence {
+    steps: Vec<Step>,
+    skip: Option<CancellationToken>,
+    fast_forward: bool,
+}
+
+impl Sequence {
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a step; its output is discarded.
+    ///
+    /// # Arguments
+    /// * `label` - Name of the step, used in the [`SequenceReport`].
+    /// * `start` - Called when the step is reached, returning the future to await.
+    pub fn step<F, Fut>(mut self, label: impl Into<String>, start: F) -> Self
+    where
+        F: FnOnce() -> Fut + 'static,
+        Fut: Future + 'static,
+    {
+        self.steps.push(Step {
+            label: label.into(),
+            start: Box::new(move || {
+                let future = start();
+                Box::pin(async move {
+                    future.await;
+                })
+            }),
+            on_skip: None,
+        });
+        self
+    }
+
+    /// Sets a hook for the last added step, called instead of finishing it when it is skipped.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if no step was added yet.
+    pub fn on_skip(mut self, on_skip: impl FnOnce() + 'static) -> Self {
+        let step = self
+            .steps
+            .last_mut()
+            .expect("ERR(godot_await):on_skip called before any step");
+        step.on_skip = Some(Box::new(on_skip));
+        self
+    }
+
+    /// Skips all remaining steps once `token` is cancelled.
+    pub fn skip_on(mut self, token: &CancellationToken) -> Self {
+        self.skip = Some(token.clone());
+        self
+    }
+
+    /// Skips every step which does not complete immediately, see also [`set_fast_forward`].
+    pub fn fast_forward(mut self, enabled: bool) -> Self {
+        self.fast_forward = enabled;
+        self
+    }
+
+    /// Runs the steps in order.
+    ///
+    /// # Returns
+    /// A future resolving to the labels of the completed and skipped steps.
+    pub async fn run(self) -> SequenceReport {
+        let fast_forward = self.fast_forward || FAST_FORWARD.get();
+        let mut report = SequenceReport::default();
+
+        for Step {
+            label,
+            start,
+            on_skip,
+        } in self.steps
+        {
+            if self.skip.as_ref().is_some_and(|token| token.is_cancelled()) {
+                report.skip(label, on_skip);
+                continue;
+            }
+
+            let mut future = start();
+            let finished = if fast_forward {
+                poll_fn(|cx| Poll::Ready(future.as_mut().poll(cx).is_ready())).await
+            } else if let Some(token) = &self.skip {
+                (&mut future).with_cancel(token).await.is_ok()
+            } else {
+                (&mut future).await;
+                true
+            };
+            drop(future);
+
+            if finished {
+                report.completed.push(label);
+            } else {
+                report.skip(label, on_skip);
+            }
+        }
+
+        report
+    }
+}
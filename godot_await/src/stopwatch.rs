@@ -0,0 +1,111 @@
+//! Game-time measurement, on the same clocks as [`wait()`](crate::wait::wait).
+//!
+//! A [`Stopwatch`] reads the time the pooled wait timers advance with, so it follows `Engine.time_scale` and pausing
+//! exactly like a wait with the same options would. The time advances once per frame, in whole frame steps.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! //in async
+//! let stopwatch = Stopwatch::start();
+//! enemy.attack_finished().await;
+//! let game_time = stopwatch.elapsed();
+//! ```
+
+use crate::scheduler::{self, TimerMode};
+
+/// Builder for creating configurable stopwatches, see [`Stopwatch::start_ex`].
+#[derive(Debug, Clone, Copy)]
+pub struct ExStopwatchBuilder {
+    mode: TimerMode,
+}
+
+impl ExStopwatchBuilder {
+    /// Set whether the stopwatch should ignore the time scale
+    ///
+    /// # Arguments
+    /// * `ignore_time_scale` - If true, the stopwatch will ignore `Engine.time_scale` and measure the real, elapsed time.
+    pub fn ignore_time_scale(mut self, ignore_time_scale: bool) -> Self {
+        self.mode.ignore_time_scale = ignore_time_scale;
+        self
+    }
+
+    /// Set whether the stopwatch should always run
+    ///
+    /// # Arguments
+    /// * `process_always` - If is false, the stopwatch will stop while the scene tree is paused.
+    pub fn process_always(mut self, process_always: bool) -> Self {
+        self.mode.process_always = process_always;
+        self
+    }
+
+    /// Set whether the stopwatch should advance in physics
+    ///
+    /// # Arguments
+    /// * `process_in_physics` - If true, the stopwatch will advance every physics frame, instead of every process frame.
+    pub fn process_in_physics(mut self, process_in_physics: bool) -> Self {
+        self.mode.process_in_physics = process_in_physics;
+        self
+    }
+
+    /// Starts the stopwatch
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the scene tree has no root. Without a `SceneTree`, this measures real time instead
+    #[inline]
+    pub fn done(self) -> Stopwatch {
+        Stopwatch {
+            mode: self.mode,
+            start: scheduler::elapsed(self.mode),
+        }
+    }
+}
+
+/// Measures game time on the scene tree, see the [module docs](self).
+#[derive(Debug, Clone, Copy)]
+pub struct Stopwatch {
+    mode: TimerMode,
+    start: f64,
+}
+
+impl Stopwatch {
+    /// Starts a stopwatch with the defaults of [`wait()`](crate::wait::wait):
+    /// scaled by `Engine.time_scale`, running while paused, advancing every process frame.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the scene tree has no root. Without a `SceneTree`, this measures real time instead
+    #[inline]
+    pub fn start() -> Self {
+        Self::start_ex().done()
+    }
+
+    /// Create a configurable stopwatch
+    ///
+    /// # Examples
+    /// ```rust,ignore
+    /// let stopwatch = Stopwatch::start_ex().process_always(false).done();
+    /// ```
+    #[inline]
+    pub fn start_ex() -> ExStopwatchBuilder {
+        ExStopwatchBuilder {
+            mode: TimerMode::default(),
+        }
+    }
+
+    /// Seconds elapsed since the stopwatch was started.
+    #[inline]
+    pub fn elapsed(&self) -> f64 {
+        scheduler::elapsed(self.mode) - self.start
+    }
+
+    /// Restarts the stopwatch, returning the seconds elapsed until now.
+    #[inline]
+    pub fn restart(&mut self) -> f64 {
+        let now = scheduler::elapsed(self.mode);
+        let elapsed = now - self.start;
+        self.start = now;
+        elapsed
+    }
+}
@@ -0,0 +1,32 @@
+//! Spawning named tasks.
+
+use std::future::Future;
+
+use godot::task::TaskHandle;
+
+/// Spawns `future` on Godot's main thread, like `godot::task::spawn`, under the given name.
+///
+/// With the `registry` feature, the name is attached to every await created by the task (see `registry::pending_awaits`),
+/// and those entries are removed once the task ends. Without it, this is equivalent to `godot::task::spawn`.
+///
+/// # Examples
+/// ```rust,ignore
+/// spawn_named("door_open", async move {
+///     button.pressed().await;
+///     door.tween_property_async("rotation", 1.5, 0.5).await.ok();
+/// });
+/// ```
+pub fn spawn_named(
+    name: impl Into<String>,
+    future: impl Future<Output = ()> + 'static,
+) -> TaskHandle {
+    #[cfg(feature = "registry")]
+    {
+        godot::task::spawn(crate::registry::Named::new(name.into(), future))
+    }
+    #[cfg(not(feature = "registry"))]
+    {
+        let _ = name;
+        godot::task::spawn(future)
+    }
+}
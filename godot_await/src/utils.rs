@@ -1,5 +1,17 @@
+use core::ops::Deref;
+#[cfg(feature = "registry")]
+use std::panic::Location;
+
+#[cfg(any(feature = "registry", feature = "diagnostics"))]
+use godot::builtin::Callable;
+use godot::builtin::Signal;
+#[cfg(any(feature = "registry", feature = "diagnostics"))]
+use godot::classes::Object;
 use godot::classes::{Engine, SceneTree};
-use godot::obj::Gd;
+#[cfg(feature = "registry")]
+use godot::obj::InstanceId;
+use godot::obj::{Gd, GodotClass};
+use godot::task::SignalFuture;
 
 #[inline]
 pub(crate) fn godot_tree() -> Gd<SceneTree> {
@@ -9,3 +21,69 @@ pub(crate) fn godot_tree() -> Gd<SceneTree> {
         .try_cast()
         .expect("ERR(godot_await):cast SceneTree failed")
 }
+
+/// Returns the scene tree, or `None` if there is no main loop or it is not a `SceneTree`.
+#[inline]
+pub(crate) fn try_godot_tree() -> Option<Gd<SceneTree>> {
+    Engine::singleton().get_main_loop()?.try_cast().ok()
+}
+
+/// Signal of `object` which is about to be awaited with `to_future()` or `to_fallible_future()`.
+#[inline]
+#[track_caller]
+pub(crate) fn object_signal<T: GodotClass>(object: &Gd<T>, signal: &'static str) -> ObjectSignal {
+    ObjectSignal {
+        signal: Signal::from_object_signal(object, signal),
+        #[cfg(feature = "registry")]
+        object_id: object.instance_id(),
+        #[cfg(feature = "registry")]
+        name: signal,
+        #[cfg(feature = "registry")]
+        location: Location::caller(),
+    }
+}
+
+/// Derefs to the awaited `Signal`. With the `registry` feature, it records the connection of the future created
+/// from it when dropped, i.e. at the end of the statement creating the future.
+pub(crate) struct ObjectSignal {
+    signal: Signal,
+    #[cfg(feature = "registry")]
+    object_id: InstanceId,
+    #[cfg(feature = "registry")]
+    name: &'static str,
+    #[cfg(feature = "registry")]
+    location: &'static Location<'static>,
+}
+
+impl Deref for ObjectSignal {
+    type Target = Signal;
+
+    fn deref(&self) -> &Signal {
+        &self.signal
+    }
+}
+
+#[cfg(feature = "registry")]
+impl Drop for ObjectSignal {
+    fn drop(&mut self) {
+        crate::registry::track_signal(self.object_id, self.name, self.location);
+    }
+}
+
+/// The callable connected last to `signal` of `object`, i.e. the one of a signal future created right before.
+#[cfg(any(feature = "registry", feature = "diagnostics"))]
+pub(crate) fn last_connection(object: &Gd<Object>, signal: &str) -> Option<Callable> {
+    object
+        .get_signal_connection_list(signal)
+        .iter_shared()
+        .last()?
+        .get("callable")?
+        .try_to::<Callable>()
+        .ok()
+}
+
+/// Resolves at the next `process_frame` of the scene tree. Used internally for polling, so never recorded.
+#[inline]
+pub(crate) fn next_process_frame() -> SignalFuture<()> {
+    Signal::from_object_signal(&godot_tree(), "process_frame").to_future::<()>()
+}
@@ -1,36 +1,49 @@
+use core::fmt;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::error::Error;
+use std::future::Future;
+
 #[cfg(feature = "future")]
 use crate::future::{Or, or};
-use crate::utils::godot_tree;
-use godot::builtin::Signal;
-use godot::classes::SceneTree;
-use godot::classes::scene_tree::ExCreateTimer;
+use crate::scheduler::{self, Fired, TimerMode};
+use crate::utils::{object_signal, try_godot_tree};
+use godot::classes::{Node, SceneTree};
+use godot::obj::Gd;
 use godot::task::SignalFuture;
 
 /// Builder for creating configurable wait timers
-pub struct ExWaitBuilder<'a>(ExCreateTimer<'a>);
+pub struct ExWaitBuilder<'a> {
+    tree: &'a mut SceneTree,
+    time_sec: f64,
+    mode: TimerMode,
+}
 
 impl ExWaitBuilder<'_> {
     /// Set whether the timer should ignore the time scale
     ///
     /// # Arguments
     /// * `ignore_time_scale` - If true, the timer will ignore `Engine.time_scale` and update with the real, elapsed time.
-    pub fn ignore_time_scale(self, ignore_time_scale: bool) -> Self {
-        ExWaitBuilder(self.0.ignore_time_scale(ignore_time_scale))
+    pub fn ignore_time_scale(mut self, ignore_time_scale: bool) -> Self {
+        self.mode.ignore_time_scale = ignore_time_scale;
+        self
     }
     /// Set whether the timer should always process
     ///
     /// # Arguments
     /// * `process_always` - If is false, the timer will be paused when setting paused to true.
-    pub fn process_always(self, process_always: bool) -> Self {
-        ExWaitBuilder(self.0.process_always(process_always))
+    pub fn process_always(mut self, process_always: bool) -> Self {
+        self.mode.process_always = process_always;
+        self
     }
 
     /// Set whether the timer should process in physics
     ///
     /// # Arguments
     /// * `process_in_physics` - If true, the timer will update at the end of the physics frame, instead of the process frame.
-    pub fn process_in_physics(self, process_in_physics: bool) -> Self {
-        ExWaitBuilder(self.0.process_in_physics(process_in_physics))
+    pub fn process_in_physics(mut self, process_in_physics: bool) -> Self {
+        self.mode.process_in_physics = process_in_physics;
+        self
     }
 
     /// Finalize the timer
@@ -41,12 +54,239 @@ impl ExWaitBuilder<'_> {
     ///
     /// Will panic if create `SceneTreeTimer` failed
     #[inline]
+    #[track_caller]
     pub fn done(self) -> SignalFuture<()> {
         let timer = self
-            .0
+            .tree
+            .create_timer_ex(self.time_sec)
+            .process_always(self.mode.process_always)
+            .process_in_physics(self.mode.process_in_physics)
+            .ignore_time_scale(self.mode.ignore_time_scale)
             .done()
             .expect("ERR(godot-await):create SceneTreeTimer failed");
-        Signal::from_object_signal(&timer, "timeout").to_future::<()>()
+        object_signal(&timer, "timeout").to_future::<()>()
+    }
+
+    /// Finalize the timer as a pooled [`Wait`], see [`wait_pooled()`]
+    ///
+    /// # Returns
+    /// A future that will resolve when the timer times out
+    /// # Panics
+    ///
+    /// Will panic if the scene tree has no root
+    #[inline]
+    #[track_caller]
+    pub fn done_pooled(self) -> Wait {
+        Wait::new(self.tree, self.time_sec, self.mode)
+    }
+}
+
+/// Future for the [`wait_pooled()`], [`ExWaitBuilder::done_pooled()`] and [`wait_realtime()`] functions.
+///
+/// All pooled waits share one scheduler node instead of creating a `SceneTreeTimer` each,
+/// with the same pause and time-scale semantics. Dropping the future cancels the timer.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Wait {
+    id: u64,
+    finished: bool,
+    /// Real-time waits have no emitter and are not recorded.
+    #[cfg(feature = "registry")]
+    registry_id: Option<u64>,
+}
+
+impl Wait {
+    #[track_caller]
+    fn new(tree: &SceneTree, time_sec: f64, mode: TimerMode) -> Self {
+        let (id, driver) = scheduler::add_timer(tree, time_sec, mode);
+        #[cfg(not(feature = "registry"))]
+        let _ = driver;
+        Wait {
+            id,
+            finished: false,
+            #[cfg(feature = "registry")]
+            registry_id: Some(crate::registry::track(
+                driver,
+                "WaitScheduler".to_string(),
+                format!("wait({time_sec}s)"),
+            )),
+        }
+    }
+
+    fn new_realtime(time_sec: f64) -> Self {
+        Wait {
+            id: scheduler::add_realtime_timer(time_sec),
+            finished: false,
+            #[cfg(feature = "registry")]
+            registry_id: None,
+        }
+    }
+
+    fn finish(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            scheduler::remove_timer(self.id);
+            #[cfg(feature = "registry")]
+            if let Some(registry_id) = self.registry_id {
+                crate::registry::untrack(registry_id);
+            }
+        }
+    }
+}
+
+impl Future for Wait {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.finished || scheduler::poll_timer(self.id, cx.waker()).is_some() {
+            self.finish();
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Wait {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// Error returned when the node of a node-bound wait leaves the tree (or is freed) before the wait is over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeftTree;
+
+impl fmt::Display for LeftTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("node left the tree before the wait was over")
+    }
+}
+
+impl Error for LeftTree {}
+
+/// Builder for waits bound to a node, see `NodeExt::wait_ex`.
+pub struct ExNodeWaitBuilder {
+    node: Gd<Node>,
+    time_sec: f64,
+    ignore_time_scale: bool,
+    process_in_physics: bool,
+}
+
+impl ExNodeWaitBuilder {
+    pub(crate) fn new(node: Gd<Node>, time_sec: f64) -> Self {
+        ExNodeWaitBuilder {
+            node,
+            time_sec,
+            ignore_time_scale: false,
+            process_in_physics: false,
+        }
+    }
+
+    /// Set whether the timer should ignore the time scale
+    ///
+    /// # Arguments
+    /// * `ignore_time_scale` - If true, the timer will ignore `Engine.time_scale` and update with the real, elapsed time.
+    pub fn ignore_time_scale(mut self, ignore_time_scale: bool) -> Self {
+        self.ignore_time_scale = ignore_time_scale;
+        self
+    }
+
+    /// Set whether the timer should process in physics
+    ///
+    /// # Arguments
+    /// * `process_in_physics` - If true, the timer will update at the end of the physics frame, instead of the process frame.
+    pub fn process_in_physics(mut self, process_in_physics: bool) -> Self {
+        self.process_in_physics = process_in_physics;
+        self
+    }
+
+    /// Finalize the timer
+    ///
+    /// # Returns
+    /// A future that will resolve when the timer times out, or to `Err(LeftTree)` if the node leaves the tree first
+    #[inline]
+    #[track_caller]
+    pub fn done(self) -> NodeWait {
+        NodeWait::new(self)
+    }
+}
+
+/// Future for `NodeExt::wait` and `NodeExt::wait_ex`.
+///
+/// Counts down only in frames where the node can process, so it pauses with the node's effective `process_mode`
+/// (e.g. a `PROCESS_MODE_WHEN_PAUSED` menu keeps counting while the tree is paused, and stops once it is unpaused).
+/// Resolves to `Err(LeftTree)` at the first frame the node is outside of the tree.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct NodeWait {
+    id: u64,
+    finished: bool,
+    #[cfg(feature = "registry")]
+    registry_id: Option<u64>,
+}
+
+impl NodeWait {
+    #[track_caller]
+    fn new(builder: ExNodeWaitBuilder) -> Self {
+        let node = builder.node;
+        if !node.is_inside_tree() {
+            return NodeWait {
+                id: scheduler::add_fired_timer(Fired::LeftTree),
+                finished: false,
+                #[cfg(feature = "registry")]
+                registry_id: None,
+            };
+        }
+
+        NodeWait {
+            id: scheduler::add_node_timer(
+                &node,
+                builder.time_sec,
+                builder.process_in_physics,
+                builder.ignore_time_scale,
+            ),
+            finished: false,
+            #[cfg(feature = "registry")]
+            registry_id: Some(crate::registry::track(
+                node.instance_id(),
+                node.get_class().to_string(),
+                format!("wait({}s)", builder.time_sec),
+            )),
+        }
+    }
+
+    fn finish(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            scheduler::remove_timer(self.id);
+            #[cfg(feature = "registry")]
+            if let Some(registry_id) = self.registry_id {
+                crate::registry::untrack(registry_id);
+            }
+        }
+    }
+}
+
+impl Future for NodeWait {
+    type Output = Result<(), LeftTree>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match scheduler::poll_timer(self.id, cx.waker()) {
+            Some(fired) => {
+                self.finish();
+                Poll::Ready(match fired {
+                    Fired::TimedOut => Ok(()),
+                    Fired::LeftTree => Err(LeftTree),
+                })
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for NodeWait {
+    fn drop(&mut self) {
+        self.finish();
     }
 }
 
@@ -59,19 +299,22 @@ impl ExWaitBuilder<'_> {
 /// # Returns
 /// A builder for configuring the wait timer
 /// # Examples
-/// ```rust
+/// ```rust,ignore
 /// //in async
 /// wait_ex(3.0).ignore_time_scale(true).done().await
 /// ```
 #[inline]
 pub fn wait_ex(tree: &mut SceneTree, time_sec: f64) -> ExWaitBuilder<'_> {
-    let timer = tree.create_timer_ex(time_sec);
-    ExWaitBuilder(timer)
+    ExWaitBuilder {
+        tree,
+        time_sec,
+        mode: TimerMode::default(),
+    }
 }
 
 /// Wait for a specified amount of time.
 ///
-/// ```
+/// ```ignore
 /// //similar to GDScript
 /// get_tree().create_timer(1.0).timeout
 /// ```
@@ -82,20 +325,102 @@ pub fn wait_ex(tree: &mut SceneTree, time_sec: f64) -> ExWaitBuilder<'_> {
 /// A future that will resolve when the wait is complete
 ///
 /// # Examples
-/// ```rust
+/// ```rust,ignore
 /// //in async
 /// wait(3.0).await
 /// ```
-/// # Panics
 ///
-/// Will panic if get `MainLoop` or cast `SceneTree` failed
+/// If the main loop is not a `SceneTree` (e.g. a custom `MainLoop`, or `@tool` code during editor startup),
+/// this falls back to real time, like [`wait_realtime()`].
 #[inline]
+#[track_caller]
 pub fn wait(time_sec: f64) -> SignalFuture<()> {
-    wait_ex(&mut godot_tree(), time_sec).done()
+    match try_godot_tree() {
+        Some(mut tree) => wait_ex(&mut tree, time_sec).done(),
+        None => wait_realtime_signal(time_sec),
+    }
+}
+
+/// Fallback of [`wait()`] without a `SceneTree`: a signal future resolving after `time_sec` seconds of real time.
+///
+/// Public for the integration tests, which always run on a `SceneTree`.
+#[doc(hidden)]
+#[inline]
+#[track_caller]
+pub fn wait_realtime_signal(time_sec: f64) -> SignalFuture<()> {
+    object_signal(&scheduler::add_realtime_emitter(time_sec), "timeout").to_future::<()>()
+}
+
+/// Like [`wait()`], but pooled: instead of a `SceneTreeTimer` and a signal connection per wait, all pooled waits share
+/// one scheduler node, which keeps their deadlines in a min-heap and wakes the futures directly.
+///
+/// Timers advance in the same frames, and with the same pause and time-scale semantics, as those of [`wait()`].
+/// Prefer it when many waits run at once, e.g. cooldowns of hundreds of enemies.
+///
+/// # Arguments
+/// * `time_sec` - The time in seconds to wait
+///
+/// # Returns
+/// A future that will resolve when the wait is complete
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// wait_pooled(3.0).await
+/// ```
+///
+/// Without a `SceneTree`, this falls back to [`wait_realtime()`].
+#[inline]
+#[track_caller]
+pub fn wait_pooled(time_sec: f64) -> Wait {
+    match try_godot_tree() {
+        Some(mut tree) => wait_ex(&mut tree, time_sec).done_pooled(),
+        None => wait_realtime(time_sec),
+    }
+}
+
+/// Wait for a specified amount of real time, without a `SceneTree`.
+///
+/// The deadline is measured with `Time.get_ticks_usec()`, so pausing and `Engine.time_scale` have no effect.
+/// Deadlines are checked after every drawn frame (`RenderingServer.frame_post_draw`) and on every [`tick_realtime()`] call;
+/// call it from your `MainLoop` callbacks when nothing is drawn, e.g. on headless servers.
+///
+/// # Arguments
+/// * `time_sec` - The time in seconds to wait
+///
+/// # Returns
+/// A future that will resolve at the first check after the deadline
+///
+/// # Examples
+/// ```rust,ignore
+/// //in async
+/// wait_realtime(0.5).await
+/// ```
+#[inline]
+pub fn wait_realtime(time_sec: f64) -> Wait {
+    Wait::new_realtime(time_sec)
+}
+
+/// Resolves all [`wait_realtime()`] futures whose deadline has passed.
+///
+/// # Examples
+/// ```rust,ignore
+/// #[godot_api]
+/// impl IMainLoop for ServerLoop {
+///     fn process(&mut self, _delta: f64) -> bool {
+///         tick_realtime();
+///         false
+///     }
+/// }
+/// ```
+#[inline]
+pub fn tick_realtime() {
+    scheduler::tick_realtime();
 }
 
 #[cfg(feature = "future")]
 #[inline]
+#[track_caller]
 pub fn wait_or(
     time_sec: f64,
     signal_future: SignalFuture<()>,
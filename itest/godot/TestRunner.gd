@@ -20,6 +20,9 @@ func _ready():
 	await get_tree().physics_frame
 
 	var allow_focus := true
+	var bench_json := ""
+	var junit_xml := ""
+	var report_json := ""
 	var filters: Array = []
 	var unrecognized_args: Array = []
 	for arg in OS.get_cmdline_user_args():
@@ -27,6 +30,16 @@ func _ready():
 			"--disallow-focus":
 				allow_focus = false
 			_:
+				if arg.begins_with("--bench-json="):
+					bench_json = arg.trim_prefix("--bench-json=")
+					continue
+				if arg.begins_with("--junit-xml="):
+					junit_xml = arg.trim_prefix("--junit-xml=")
+					continue
+				if arg.begins_with("--report-json="):
+					report_json = arg.trim_prefix("--report-json=")
+					continue
+
 				if not arg.begins_with("[") or not arg.ends_with("]"):
 					unrecognized_args.push_back(arg)
 
@@ -42,17 +55,22 @@ func _ready():
 
 	# Run benchmarks after all synchronous and asynchronous tests have completed.
 	var run_benchmarks = func (success: bool):
-		if success:
-			rust_runner.run_all_benchmarks(self)
-
 		var exit_code: int = 0 if success else 1
-		get_tree().quit(exit_code)
+		var quit = func ():
+			get_tree().quit(exit_code)
+
+		if success:
+			# Async benchmarks span several frames, so quit once they are done.
+			rust_runner.run_all_benchmarks(self, bench_json, quit)
+		else:
+			quit.call()
 
 	rust_runner.run_all_tests(
 		allow_focus,
 		self,
 		filters,
-
+		junit_xml,
+		report_json,
 		run_benchmarks
 	)
 
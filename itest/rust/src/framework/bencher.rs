@@ -17,8 +17,15 @@
 // Instead, we focus on min (fastest run) and median -- even median may vary quite a bit between runs; but it gives an idea of the distribution.
 // See also https://easyperf.net/blog/2019/12/30/Comparing-performance-measurements#average-median-minimum.
 
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 
+use crate::framework::frame_count;
+
 const WARMUP_RUNS: usize = 200;
 const TEST_RUNS: usize = 501; // uneven, so median need not be interpolated.
 const METRIC_COUNT: usize = 2;
@@ -76,3 +83,131 @@ fn calculate_stats(times: Vec<Duration>) -> BenchResult {
         stats: [min, median],
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Async benchmarks
+
+// Async benchmarks span several frames, so they are run far less often than synchronous ones.
+const ASYNC_WARMUP_RUNS: usize = 3;
+const ASYNC_TEST_RUNS: usize = 21; // uneven, so median need not be interpolated.
+
+/// Factory of the future measured by an async benchmark.
+pub type AsyncBenchFn = fn() -> Pin<Box<dyn Future<Output = ()>>>;
+
+pub struct AsyncBenchResult {
+    /// Wall time per future.
+    pub stats: [Duration; METRIC_COUNT],
+    /// Median number of frames until the benchmark completed.
+    pub frames: u64,
+    /// Average number of times the futures wrapped with [`count_polls`] were polled, per future.
+    pub polls: f64,
+    /// Average number of Rust heap allocations, per future. Allocations inside Godot are not counted.
+    pub allocations: f64,
+}
+
+pub fn async_metrics() -> [&'static str; METRIC_COUNT + 3] {
+    ["min", "median", "frames", "polls", "allocs"]
+}
+
+pub async fn run_async_benchmark(code: AsyncBenchFn, inner_repetitions: usize) -> AsyncBenchResult {
+    for _ in 0..ASYNC_WARMUP_RUNS {
+        code().await;
+    }
+
+    let mut times = Vec::with_capacity(ASYNC_TEST_RUNS);
+    let mut frames = Vec::with_capacity(ASYNC_TEST_RUNS);
+    let mut polls = 0;
+    let mut allocations = 0;
+    for _ in 0..ASYNC_TEST_RUNS {
+        let future = code();
+        let start_polls = POLLS.load(Ordering::Relaxed);
+        let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let start_frame = frame_count();
+        let start = Instant::now();
+        COUNT_ALLOCATIONS.store(true, Ordering::Relaxed);
+
+        future.await;
+
+        COUNT_ALLOCATIONS.store(false, Ordering::Relaxed);
+        times.push(start.elapsed() / inner_repetitions as u32);
+        frames.push(frame_count() - start_frame);
+        polls += POLLS.load(Ordering::Relaxed) - start_polls;
+        allocations += ALLOCATIONS.load(Ordering::Relaxed) - start_allocations;
+    }
+    times.sort();
+    frames.sort();
+
+    let per_future = (ASYNC_TEST_RUNS * inner_repetitions) as f64;
+    AsyncBenchResult {
+        stats: [times[0], times[ASYNC_TEST_RUNS / 2]],
+        frames: frames[ASYNC_TEST_RUNS / 2],
+        polls: polls as f64 / per_future,
+        allocations: allocations as f64 / per_future,
+    }
+}
+
+static POLLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps a future measured by an async benchmark, so that its polls count towards the `polls` metric.
+///
+/// Only wrapped futures are counted, not the benchmark task itself (e.g. the `join_all` around them).
+pub fn count_polls<F: Future>(inner: F) -> CountPolls<F> {
+    CountPolls { inner }
+}
+
+/// Future returned by [`count_polls`].
+pub struct CountPolls<F> {
+    inner: F,
+}
+
+impl<F: Future> Future for CountPolls<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        POLLS.fetch_add(1, Ordering::Relaxed);
+        // SAFETY: `inner` is never moved out of the pinned `CountPolls`.
+        let inner = unsafe { self.map_unchecked_mut(|this| &mut this.inner) };
+        inner.poll(cx)
+    }
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static COUNT_ALLOCATIONS: AtomicBool = AtomicBool::new(false);
+
+/// System allocator which counts allocations, for the `allocs` metric of async benchmarks.
+///
+/// It only counts while an async benchmark is measured. Everything else (all itests and synchronous benchmarks)
+/// pays one relaxed atomic load per allocation.
+struct CountingAllocator;
+
+impl CountingAllocator {
+    fn count(&self) {
+        if COUNT_ALLOCATIONS.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.count();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
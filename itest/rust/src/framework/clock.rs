@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Deterministic clock for time-based async tests.
+//
+// The itest project runs with `--fixed-fps 64` (see check.sh), so every process frame advances the scene tree by exactly
+// 1/64 s, no matter how long it takes in real time. 1/64 is a power of two, so timers accumulate no rounding error either.
+// Tests can then assert that a future resolved after exactly N frames, instead of comparing `Instant`s with tolerances.
+
+use std::cell::Cell;
+use std::future::Future;
+
+use godot::builtin::{Callable, Signal, Variant};
+use godot::classes::{Engine, SceneTree};
+use godot::global::godot_warn;
+use godot::obj::Gd;
+
+/// Frame rate the itest project is run with.
+pub const FIXED_FPS: u32 = 64;
+
+/// Simulated seconds per process frame.
+pub const FRAME_STEP: f64 = 1.0 / FIXED_FPS as f64;
+
+thread_local! {
+    static FRAMES: Cell<Option<u64>> = const { Cell::new(None) };
+    static WARNED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Number of process frames a future was pending for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed {
+    /// Process frames, including the one the future was created in (its timers already advance in that frame).
+    pub frames: u64,
+}
+
+impl Elapsed {
+    /// Simulated time, in seconds.
+    pub fn seconds(&self) -> f64 {
+        self.frames as f64 * FRAME_STEP
+    }
+}
+
+/// Number of frames a timer of `time_sec` seconds needs to time out.
+pub fn frames_for(time_sec: f64) -> u64 {
+    (time_sec / FRAME_STEP).ceil() as u64
+}
+
+/// Waits for the start of the next process frame, then awaits the future returned by `make_future`.
+///
+/// # Returns
+/// The output of the future, and the number of frames it took.
+///
+/// Without `--fixed-fps 64` (e.g. when run from the editor), frames take real time and the counts are not
+/// deterministic; a warning is printed once, and assertions on exact frame counts may fail.
+pub async fn measure_frames<F: Future>(make_future: impl FnOnce() -> F) -> (F::Output, Elapsed) {
+    let tree = scene_tree();
+    frame_count();
+
+    Signal::from_object_signal(&tree, "process_frame")
+        .to_future::<()>()
+        .await;
+    let root = tree.get_root().expect("scene tree has a root");
+    if root.get_process_delta_time() != FRAME_STEP && !WARNED.replace(true) {
+        godot_warn!(
+            "time-based itests expect `--fixed-fps {FIXED_FPS}` (see check.sh); frame counts are not deterministic in this run"
+        );
+    }
+
+    let start = frame_count();
+    let output = make_future().await;
+    let elapsed = Elapsed {
+        frames: frame_count() - start + 1,
+    };
+
+    (output, elapsed)
+}
+
+fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .expect("main loop exists")
+        .try_cast()
+        .expect("main loop is a SceneTree")
+}
+
+/// Number of `process_frame` emissions since the first call.
+pub fn frame_count() -> u64 {
+    if let Some(frames) = FRAMES.get() {
+        return frames;
+    }
+    FRAMES.set(Some(0));
+
+    let counter = Callable::from_local_fn("itest_frame_counter", |_| {
+        FRAMES.set(FRAMES.get().map(|frames| frames + 1));
+        Ok(Variant::nil())
+    });
+    scene_tree().connect("process_frame", &counter);
+    0
+}
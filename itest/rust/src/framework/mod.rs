@@ -12,9 +12,14 @@ use std::collections::HashSet;
 use std::panic;
 
 mod bencher;
+mod clock;
+mod json;
+mod report;
 mod runner;
 
 pub use bencher::*;
+pub use clock::*;
+pub use json::*;
 pub use runner::*;
 
 /// Allow re-import as `crate::framework::itest`.
@@ -28,6 +33,60 @@ sys::plugin_registry!(pub(crate) __GODOT_ITEST: RustTestCase);
 #[cfg(since_api = "4.2")]
 sys::plugin_registry!(pub(crate) __GODOT_ASYNC_ITEST: AsyncRustTestCase);
 sys::plugin_registry!(pub(crate) __GODOT_BENCH: RustBenchmark);
+// Registers the benchmarks declared with `async_bench!`.
+#[cfg(since_api = "4.2")]
+sys::plugin_registry!(pub(crate) __GODOT_ASYNC_BENCH: AsyncRustBenchmark);
+// Registers the timeouts set with `itest_timeout!`.
+#[cfg(since_api = "4.2")]
+sys::plugin_registry!(pub(crate) __GODOT_ITEST_TIMEOUT: AsyncTestTimeout);
+
+/// Overrides the timeout of the `#[itest(async)]` test `$test` in the current file, in (simulated) seconds.
+///
+/// With `realtime`, the timeout is measured in real seconds instead, for tests waiting on the wall clock
+/// (which spans an unknown number of simulated frames).
+///
+/// Tests without an override fail after [`DEFAULT_ASYNC_TIMEOUT_SEC`].
+macro_rules! itest_timeout {
+    ($test:ident, realtime $timeout_sec:expr) => {
+        godot::sys::plugin_add!(__GODOT_ITEST_TIMEOUT in crate::framework; crate::framework::AsyncTestTimeout {
+            name: stringify!($test),
+            file: file!(),
+            timeout_sec: $timeout_sec,
+            realtime: true,
+        });
+    };
+    ($test:ident, $timeout_sec:expr) => {
+        godot::sys::plugin_add!(__GODOT_ITEST_TIMEOUT in crate::framework; crate::framework::AsyncTestTimeout {
+            name: stringify!($test),
+            file: file!(),
+            timeout_sec: $timeout_sec,
+            realtime: false,
+        });
+    };
+}
+#[cfg(since_api = "4.2")]
+pub(crate) use itest_timeout;
+
+/// Declares the async function `$bench` as a benchmark, measuring the time, frames, polls and allocations it takes to complete.
+///
+/// `repetitions` is the number of futures the benchmark awaits, so that metrics are reported per future.
+/// Polls are only counted for the futures wrapped with [`count_polls`].
+macro_rules! async_bench {
+    ($bench:ident) => {
+        crate::framework::async_bench!($bench, repetitions = 1);
+    };
+    ($bench:ident, repetitions = $repetitions:expr) => {
+        godot::sys::plugin_add!(__GODOT_ASYNC_BENCH in crate::framework; crate::framework::AsyncRustBenchmark {
+            name: stringify!($bench),
+            file: file!(),
+            line: line!(),
+            function: || Box::pin($bench()),
+            repetitions: $repetitions,
+        });
+    };
+}
+#[cfg(since_api = "4.2")]
+pub(crate) use async_bench;
 
 /// Finds all `#[itest]` tests.
 fn collect_rust_tests(filters: &[String]) -> (Vec<RustTestCase>, HashSet<&str>, bool) {
@@ -87,6 +146,20 @@ fn collect_async_rust_tests(
     (tests, all_files, is_focus_run)
 }
 
+/// Finds the timeout of an `#[itest(async)]` test, and whether it is measured in real time.
+#[cfg(since_api = "4.2")]
+fn find_async_timeout(test: &AsyncRustTestCase) -> (f64, bool) {
+    let mut found = (DEFAULT_ASYNC_TIMEOUT_SEC, false);
+
+    sys::plugin_foreach!(__GODOT_ITEST_TIMEOUT; |timeout: &AsyncTestTimeout| {
+        if timeout.name == test.name && timeout.file == test.file {
+            found = (timeout.timeout_sec, timeout.realtime);
+        }
+    });
+
+    found
+}
+
 /// Finds all `#[bench]` benchmarks.
 fn collect_rust_benchmarks() -> (Vec<RustBenchmark>, usize) {
     let mut all_files = HashSet::new();
@@ -103,6 +176,23 @@ fn collect_rust_benchmarks() -> (Vec<RustBenchmark>, usize) {
     (benchmarks, all_files.len())
 }
 
+/// Finds all `async_bench!` benchmarks.
+#[cfg(since_api = "4.2")]
+fn collect_async_rust_benchmarks() -> (Vec<AsyncRustBenchmark>, usize) {
+    let mut all_files = HashSet::new();
+    let mut benchmarks: Vec<AsyncRustBenchmark> = vec![];
+
+    sys::plugin_foreach!(__GODOT_ASYNC_BENCH; |bench: &AsyncRustBenchmark| {
+        benchmarks.push(*bench);
+        all_files.insert(bench.file);
+    });
+
+    // Sort alphabetically for deterministic run order
+    benchmarks.sort_by_key(|bench| bench.file);
+
+    (benchmarks, all_files.len())
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Shared types
 #[derive(Clone)]
@@ -135,6 +225,19 @@ pub struct AsyncRustTestCase {
     pub function: fn(&TestContext) -> godot::task::TaskHandle,
 }
 
+/// Simulated seconds after which an `#[itest(async)]` test is cancelled and fails, unless overridden with `itest_timeout!`.
+#[cfg(since_api = "4.2")]
+pub const DEFAULT_ASYNC_TIMEOUT_SEC: f64 = 5.0;
+
+#[cfg(since_api = "4.2")]
+#[derive(Copy, Clone)]
+pub struct AsyncTestTimeout {
+    pub name: &'static str,
+    pub file: &'static str,
+    pub timeout_sec: f64,
+    pub realtime: bool,
+}
+
 #[derive(Copy, Clone)]
 pub struct RustBenchmark {
     pub name: &'static str,
@@ -145,6 +248,17 @@ pub struct RustBenchmark {
     pub repetitions: usize,
 }
 
+#[cfg(since_api = "4.2")]
+#[derive(Copy, Clone)]
+pub struct AsyncRustBenchmark {
+    pub name: &'static str,
+    pub file: &'static str,
+    #[allow(dead_code)]
+    pub line: u32,
+    pub function: AsyncBenchFn,
+    pub repetitions: usize,
+}
+
 pub fn passes_filter(filters: &[String], test_name: &str) -> bool {
     filters.is_empty() || filters.iter().any(|x| test_name.contains(x))
 }
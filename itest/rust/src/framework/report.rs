@@ -0,0 +1,219 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Machine-readable test reports (JUnit XML and JSON), for CI.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::panic;
+use std::sync::Once;
+use std::time::Duration;
+
+use super::runner::{extract_file_subtitle, TestOutcome};
+use super::Json;
+
+/// Result of a single test, as listed in the reports.
+#[derive(Debug, Clone)]
+pub struct TestRecord {
+    pub name: &'static str,
+    pub file: &'static str,
+    pub is_async: bool,
+    pub outcome: TestOutcome,
+    pub duration: Duration,
+    /// Panic message or timeout of a failed test.
+    pub message: Option<String>,
+}
+
+/// Summary of a test run, as listed in the reports.
+pub struct TestRunInfo<'a> {
+    pub records: &'a [TestRecord],
+    pub duration: Duration,
+    pub focus_run: bool,
+    pub filters: &'a [String],
+}
+
+thread_local! {
+    static FAILURE_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Records panic messages, so they can be attached to the failed test. Safe to call several times.
+pub fn install_panic_recorder() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string());
+            let message = match info.location() {
+                Some(location) => format!("{message} (at {location})"),
+                None => message,
+            };
+            set_failure_message(message);
+
+            previous(info);
+        }));
+    });
+}
+
+/// Sets the failure message of the running test, unless one was already recorded.
+pub fn set_failure_message(message: String) {
+    // Panics during thread-local destruction cannot be recorded anymore.
+    let _ = FAILURE_MESSAGE.try_with(|failure| {
+        failure.borrow_mut().get_or_insert(message);
+    });
+}
+
+/// Takes the failure message recorded since the last call.
+pub fn take_failure_message() -> Option<String> {
+    FAILURE_MESSAGE.with_borrow_mut(Option::take)
+}
+
+fn outcome_name(outcome: &TestOutcome) -> &'static str {
+    match outcome {
+        TestOutcome::Passed => "passed",
+        TestOutcome::Failed => "failed",
+        TestOutcome::Skipped => "skipped",
+    }
+}
+
+pub fn json_report(run: &TestRunInfo) -> Json {
+    let count = |outcome: &str| {
+        let count = run
+            .records
+            .iter()
+            .filter(|record| outcome_name(&record.outcome) == outcome)
+            .count();
+        Json::Number(count as f64)
+    };
+
+    let tests = run
+        .records
+        .iter()
+        .map(|record| {
+            Json::Object(vec![
+                ("name", Json::string(record.name)),
+                ("file", Json::string(extract_file_subtitle(record.file))),
+                ("async", Json::Bool(record.is_async)),
+                ("outcome", Json::string(outcome_name(&record.outcome))),
+                ("duration_sec", Json::Number(record.duration.as_secs_f64())),
+                (
+                    "message",
+                    record.message.clone().map_or(Json::Null, Json::String),
+                ),
+            ])
+        })
+        .collect();
+
+    Json::Object(vec![
+        ("total", Json::Number(run.records.len() as f64)),
+        ("passed", count("passed")),
+        ("failed", count("failed")),
+        ("skipped", count("skipped")),
+        ("duration_sec", Json::Number(run.duration.as_secs_f64())),
+        ("focus_run", Json::Bool(run.focus_run)),
+        (
+            "filters",
+            Json::Array(run.filters.iter().map(Json::string).collect()),
+        ),
+        ("tests", Json::Array(tests)),
+    ])
+}
+
+pub fn junit_report(run: &TestRunInfo) -> String {
+    // One <testsuite> per file, sorted by file name.
+    let mut suites: BTreeMap<&str, Vec<&TestRecord>> = BTreeMap::new();
+    for record in run.records {
+        suites
+            .entry(extract_file_subtitle(record.file))
+            .or_default()
+            .push(record);
+    }
+
+    let count = |records: &[&TestRecord], outcome: &str| {
+        records
+            .iter()
+            .filter(|record| outcome_name(&record.outcome) == outcome)
+            .count()
+    };
+    let all = run.records.iter().collect::<Vec<_>>();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"itest\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+        all.len(),
+        count(&all, "failed"),
+        count(&all, "skipped"),
+        run.duration.as_secs_f64()
+    );
+
+    for (file, records) in suites {
+        let time: Duration = records.iter().map(|record| record.duration).sum();
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            escape_xml(file),
+            records.len(),
+            count(&records, "failed"),
+            count(&records, "skipped"),
+            time.as_secs_f64()
+        );
+        if run.focus_run {
+            xml.push_str("    <properties>\n      <property name=\"focus_run\" value=\"true\"/>\n    </properties>\n");
+        }
+
+        for record in records {
+            let _ = write!(
+                xml,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                escape_xml(record.name),
+                escape_xml(file),
+                record.duration.as_secs_f64()
+            );
+            match record.outcome {
+                TestOutcome::Passed => xml.push_str("/>\n"),
+                TestOutcome::Skipped => xml.push_str(">\n      <skipped/>\n    </testcase>\n"),
+                TestOutcome::Failed => {
+                    let message = record.message.as_deref().unwrap_or("test failed");
+                    let _ = writeln!(
+                        xml,
+                        ">\n      <failure message=\"{}\"/>\n    </testcase>",
+                        escape_xml(message)
+                    );
+                }
+            }
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
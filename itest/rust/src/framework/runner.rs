@@ -8,15 +8,16 @@
 use std::time::{Duration, Instant};
 
 use godot::builtin::{Array, Callable, GString, Variant, VariantArray};
-use godot::classes::{Node, Os};
+use godot::classes::{Node, Os, ProjectSettings};
 use godot::meta::ToGodot;
 use godot::obj::Gd;
 use godot::register::{godot_api, GodotClass};
 
-use crate::framework::{bencher, BenchResult, RustBenchmark, RustTestCase, TestContext};
+use crate::framework::report::{self, TestRecord, TestRunInfo};
+use crate::framework::{bencher, BenchResult, Json, RustBenchmark, RustTestCase, TestContext};
 
 #[cfg(since_api = "4.2")]
-use super::AsyncRustTestCase;
+use super::{AsyncBenchResult, AsyncRustBenchmark, AsyncRustTestCase};
 
 #[derive(Debug, Clone, Default)]
 struct TestStats {
@@ -24,6 +25,7 @@ struct TestStats {
     passed: usize,
     skipped: usize,
     failed_list: Vec<String>,
+    records: Vec<TestRecord>,
 }
 
 #[derive(GodotClass, Debug)]
@@ -46,9 +48,12 @@ impl IntegrationTests {
         scene_tree: Gd<Node>,
         filters: VariantArray,
         // property_tests: Gd<Node>,
+        junit_xml: GString,
+        report_json: GString,
         on_finished: Callable,
     ) {
         println!("{}Run{} Godot integration tests...", FMT_CYAN_BOLD, FMT_END);
+        report::install_panic_recorder();
         let filters: Vec<String> = filters.iter_shared().map(|v| v.to::<String>()).collect();
         // let gdscript_tests = gdscript_tests
         //     .iter_shared()
@@ -92,9 +97,12 @@ impl IntegrationTests {
         //     None
         // };
 
+        // Async tests and the JUnit/JSON reports run from here. The gate only mirrors upstream gdext: godot_await builds on
+        // `godot::task`, which needs Godot 4.2, so itest cannot be compiled for older APIs anyway.
         #[cfg(since_api = "4.2")]
         {
             let stats = self.stats.clone();
+            let focus_run = self.focus_run;
 
             let on_finalize_test = move |stats| {
                 let rust_async_time = clock.elapsed() - rust_time;
@@ -108,6 +116,19 @@ impl IntegrationTests {
                     allow_focus,
                 );
 
+                let run = TestRunInfo {
+                    records: &stats.records,
+                    duration: rust_time + rust_async_time,
+                    focus_run,
+                    filters: &filters,
+                };
+                if !junit_xml.is_empty() {
+                    write_report(&junit_xml, &report::junit_report(&run));
+                }
+                if !report_json.is_empty() {
+                    write_report(&report_json, &report::json_report(&run).to_json());
+                }
+
                 // Calling deferred to break a potentially synchronous call stack and avoid re-entrancy.
                 on_finished.call_deferred(&[result.to_variant()]);
             };
@@ -123,9 +144,15 @@ impl IntegrationTests {
     }
 
     #[func]
-    fn run_all_benchmarks(&mut self, scene_tree: Gd<Node>) {
+    fn run_all_benchmarks(
+        &mut self,
+        scene_tree: Gd<Node>,
+        bench_json: GString,
+        on_finished: Callable,
+    ) {
         if self.focus_run {
             println!("  Benchmarks skipped (focused run).");
+            on_finished.call_deferred(&[]);
             return;
         }
 
@@ -139,11 +166,32 @@ impl IntegrationTests {
             benchmarks.len(),
             rust_file_count
         );
+        #[cfg(since_api = "4.2")]
+        let (async_benchmarks, async_file_count) = super::collect_async_rust_benchmarks();
+        #[cfg(since_api = "4.2")]
+        println!(
+            "  Rust: found {} async benchmarks in {} files.",
+            async_benchmarks.len(),
+            async_file_count
+        );
 
         let clock = Instant::now();
-        self.run_rust_benchmarks(benchmarks, scene_tree);
-        let total_time = clock.elapsed();
-        self.conclude_benchmarks(total_time);
+        #[allow(unused_mut)]
+        let mut results = self.run_rust_benchmarks(benchmarks, scene_tree);
+
+        // Async benchmarks span several frames, so the report is written once they are done.
+        #[cfg(since_api = "4.2")]
+        godot::task::spawn(async move {
+            results.extend(Self::run_async_rust_benchmarks(async_benchmarks).await);
+            Self::conclude_benchmarks(clock.elapsed(), results, &bench_json);
+            on_finished.call_deferred(&[]);
+        });
+
+        #[cfg(not(since_api = "4.2"))]
+        {
+            Self::conclude_benchmarks(clock.elapsed(), results, &bench_json);
+            on_finished.call_deferred(&[]);
+        }
     }
 
     fn warn_if_debug(&self) {
@@ -170,9 +218,11 @@ impl IntegrationTests {
             print_test_pre(test.name, test.file, last_file.as_deref(), false);
             last_file = Some(test.file.to_string());
 
+            let start = Instant::now();
             let outcome = run_rust_test(&test, &ctx);
 
-            Self::update_stats(&mut self.stats, &outcome, test.file, test.name);
+            let record = test_record(test.name, test.file, false, outcome, start.elapsed());
+            Self::update_stats(&mut self.stats, record);
             print_test_post(test.name, outcome);
         }
     }
@@ -211,8 +261,10 @@ impl IntegrationTests {
         print_test_pre(test.name, test.file, last_file.as_deref(), true);
         last_file.replace(test.file.to_string());
 
+        let start = Instant::now();
         run_async_rust_test(&test, &ctx.clone(), move |outcome| {
-            Self::update_stats(&mut stats, &outcome, test.file, test.name);
+            let record = test_record(test.name, test.file, true, outcome, start.elapsed());
+            Self::update_stats(&mut stats, record);
             print_test_post(test.name, outcome);
 
             if let Some(next) = tests_iter.next() {
@@ -294,7 +346,11 @@ impl IntegrationTests {
         }
     }
 
-    fn run_rust_benchmarks(&mut self, benchmarks: Vec<RustBenchmark>, _scene_tree: Gd<Node>) {
+    fn run_rust_benchmarks(
+        &mut self,
+        benchmarks: Vec<RustBenchmark>,
+        _scene_tree: Gd<Node>,
+    ) -> Vec<Json> {
         // let ctx = TestContext { scene_tree };
 
         print!("\n{FMT_CYAN}{space}", space = " ".repeat(36));
@@ -303,37 +359,92 @@ impl IntegrationTests {
         }
         print!("{FMT_END}");
 
+        let mut results = Vec::new();
         let mut last_file = None;
         for bench in benchmarks {
             print_bench_pre(bench.name, bench.file, last_file.as_deref());
             last_file = Some(bench.file.to_string());
 
             let result = bencher::run_benchmark(bench.function, bench.repetitions);
+            results.push(bench_json(bench.name, bench.file, &result.stats, vec![]));
             print_bench_post(result);
         }
+
+        results
+    }
+
+    #[cfg(since_api = "4.2")]
+    async fn run_async_rust_benchmarks(benchmarks: Vec<AsyncRustBenchmark>) -> Vec<Json> {
+        print!("\n{FMT_CYAN}{space}", space = " ".repeat(36));
+        for metrics in bencher::async_metrics() {
+            print!("{:>13}", metrics);
+        }
+        print!("{FMT_END}");
+
+        let mut results = Vec::new();
+        let mut last_file = None;
+        for bench in benchmarks {
+            print_bench_pre(bench.name, bench.file, last_file.as_deref());
+            last_file = Some(bench.file.to_string());
+
+            // Godot may print while the benchmark runs.
+            use std::io::Write;
+            std::io::stdout().flush().expect("flush stdout");
+
+            let result = bencher::run_async_benchmark(bench.function, bench.repetitions).await;
+            results.push(bench_json(
+                bench.name,
+                bench.file,
+                &result.stats,
+                vec![
+                    ("repetitions", Json::Number(bench.repetitions as f64)),
+                    ("frames", Json::Number(result.frames as f64)),
+                    ("polls_per_future", Json::Number(result.polls)),
+                    ("allocations_per_future", Json::Number(result.allocations)),
+                ],
+            ));
+            print_async_bench_post(result);
+        }
+
+        results
     }
 
-    fn conclude_benchmarks(&self, total_time: Duration) {
+    fn conclude_benchmarks(total_time: Duration, results: Vec<Json>, bench_json: &GString) {
         let secs = total_time.as_secs_f32();
         println!("\nBenchmarks completed in {secs:.2}s.");
+
+        if !bench_json.is_empty() {
+            let report = Json::Object(vec![
+                ("godot_debug", Json::Bool(Os::singleton().is_debug_build())),
+                ("rust_debug", Json::Bool(cfg!(debug_assertions))),
+                // `registry` and `diagnostics` add tracking to every signal future, see `check.sh bench`.
+                (
+                    "godot_await_features",
+                    Json::Array(
+                        godot_await::ENABLED_FEATURES
+                            .iter()
+                            .map(|&feature| Json::string(feature))
+                            .collect(),
+                    ),
+                ),
+                ("benchmarks", Json::Array(results)),
+            ]);
+            write_report(bench_json, &report.to_json());
+        }
     }
 
-    fn update_stats(
-        stats: &mut TestStats,
-        outcome: &TestOutcome,
-        test_file: &str,
-        test_name: &str,
-    ) {
+    fn update_stats(stats: &mut TestStats, record: TestRecord) {
         stats.total += 1;
-        match outcome {
+        match record.outcome {
             TestOutcome::Passed => stats.passed += 1,
             TestOutcome::Failed => stats.failed_list.push(format!(
                 "{} > {}",
-                extract_file_subtitle(test_file),
-                test_name
+                extract_file_subtitle(record.file),
+                record.name
             )),
             TestOutcome::Skipped => stats.skipped += 1,
         }
+        stats.records.push(record);
     }
 }
 
@@ -349,10 +460,31 @@ const FMT_YELLOW: &str = "\x1b[33m";
 const FMT_RED: &str = "\x1b[31m";
 const FMT_END: &str = "\x1b[0m";
 
+fn test_record(
+    name: &'static str,
+    file: &'static str,
+    is_async: bool,
+    outcome: TestOutcome,
+    duration: Duration,
+) -> TestRecord {
+    // Always taken, so that messages of expected panics don't leak into the next test.
+    let message = report::take_failure_message();
+
+    TestRecord {
+        name,
+        file,
+        is_async,
+        outcome,
+        duration,
+        message: message.filter(|_| matches!(outcome, TestOutcome::Failed)),
+    }
+}
+
 fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> TestOutcome {
     if test.skipped {
         return TestOutcome::Skipped;
     }
+    report::take_failure_message();
 
     // This will appear in all panics, but those inside expect_panic() are suppressed.
     // So the "itest failed" message will only appear for unexpected panics, where tests indeed fail.
@@ -373,6 +505,7 @@ fn run_async_rust_test(
     if test.skipped {
         return on_test_finished(TestOutcome::Skipped);
     }
+    report::take_failure_message();
 
     // Explicit type to prevent tests from returning a value
     let err_context = || format!("itest `{}` failed", test.name);
@@ -383,7 +516,71 @@ fn run_async_rust_test(
         return on_test_finished(TestOutcome::Failed);
     };
 
-    check_async_test_task(task_handle, on_test_finished, ctx);
+    let (timeout_sec, realtime) = super::find_async_timeout(test);
+    let deadline = AsyncTestDeadline {
+        name: test.name,
+        timeout_sec,
+        realtime,
+        elapsed_sec: 0.0,
+        started: Instant::now(),
+    };
+    check_async_test_task(task_handle, on_test_finished, ctx, deadline);
+}
+
+/// Time budget of a running async test, counted in simulated seconds so that it is deterministic under `--fixed-fps`,
+/// or in real seconds for tests declared with `itest_timeout!(test, realtime secs)`.
+#[cfg(since_api = "4.2")]
+struct AsyncTestDeadline {
+    name: &'static str,
+    timeout_sec: f64,
+    realtime: bool,
+    elapsed_sec: f64,
+    started: Instant,
+}
+
+#[cfg(since_api = "4.2")]
+impl AsyncTestDeadline {
+    fn is_exceeded(&self) -> bool {
+        let elapsed_sec = if self.realtime {
+            self.started.elapsed().as_secs_f64()
+        } else {
+            self.elapsed_sec
+        };
+        elapsed_sec >= self.timeout_sec
+    }
+
+    fn report_timeout(&self) {
+        let clock = if self.realtime { " of real time" } else { "" };
+        let message = format!("timed out after {:.2}s{clock}", self.timeout_sec);
+        println!("\n{FMT_RED}itest `{}` {message}{FMT_END}", self.name);
+        report::set_failure_message(message);
+
+        self.report_pending();
+    }
+
+    #[cfg(feature = "await-instrumentation")]
+    fn report_pending(&self) {
+        // Awaits created before the test started belong to other tests.
+        let pending = godot_await::registry::pending_awaits()
+            .into_iter()
+            .filter(|pending| pending.created >= self.started)
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            println!("  No pending godot_await future.");
+        } else {
+            println!("  Pending godot_await futures:");
+            for pending in pending {
+                println!("  * {pending}");
+            }
+        }
+    }
+
+    #[cfg(not(feature = "await-instrumentation"))]
+    fn report_pending(&self) {
+        println!(
+            "  Pending godot_await futures are listed with the `await-instrumentation` feature."
+        );
+    }
 }
 
 #[cfg(since_api = "4.2")]
@@ -391,6 +588,7 @@ fn check_async_test_task(
     task_handle: godot::task::TaskHandle,
     on_test_finished: impl FnOnce(TestOutcome) + 'static,
     ctx: &TestContext,
+    mut deadline: AsyncTestDeadline,
 ) {
     use godot::classes::object::ConnectFlags;
     use godot::obj::EngineBitfield;
@@ -404,11 +602,25 @@ fn check_async_test_task(
         return;
     }
 
+    if deadline.is_exceeded() {
+        deadline.report_timeout();
+        task_handle.cancel();
+        on_test_finished(TestOutcome::Failed);
+
+        return;
+    }
+
     let next_ctx = ctx.clone();
     let mut callback = Some(on_test_finished);
     let mut probably_task_handle = Some(task_handle);
+    let mut probably_deadline = Some(deadline);
 
     let deferred = Callable::from_local_fn("run_async_rust_test", move |_| {
+        let mut deadline = probably_deadline
+            .take()
+            .expect("Callable will only be called once!");
+        deadline.elapsed_sec += next_ctx.scene_tree.get_process_delta_time();
+
         check_async_test_task(
             probably_task_handle
                 .take()
@@ -417,6 +629,7 @@ fn check_async_test_task(
                 .take()
                 .expect("Callable should not be called multiple times!"),
             &next_ctx,
+            deadline,
         );
         Ok(Variant::nil())
     });
@@ -450,7 +663,7 @@ fn print_file_header(file: &str, last_file: Option<&str>) {
     }
 }
 
-fn extract_file_subtitle(file: &str) -> &str {
+pub(super) fn extract_file_subtitle(file: &str) -> &str {
     if let Some(sep_pos) = file.rfind(['/', '\\']) {
         &file[sep_pos + 1..]
     } else {
@@ -490,6 +703,45 @@ fn print_bench_post(result: BenchResult) {
     println!();
 }
 
+#[cfg(since_api = "4.2")]
+fn print_async_bench_post(result: AsyncBenchResult) {
+    for stat in result.stats.iter() {
+        print!(" {:>10.3}μs", stat.as_nanos() as f64 / 1000.0);
+    }
+    print!(" {:>12}", result.frames);
+    print!(" {:>12.2}", result.polls);
+    println!(" {:>12.2}", result.allocations);
+}
+
+fn bench_json(
+    name: &str,
+    file: &str,
+    stats: &[Duration],
+    extra: Vec<(&'static str, Json)>,
+) -> Json {
+    let micros = |stat: &Duration| Json::Number(stat.as_nanos() as f64 / 1000.0);
+
+    let mut fields = vec![
+        ("name", Json::string(name)),
+        ("file", Json::string(extract_file_subtitle(file))),
+        ("min_us", micros(&stats[0])),
+        ("median_us", micros(&stats[1])),
+    ];
+    fields.extend(extra);
+    Json::Object(fields)
+}
+
+/// Writes `report` to `path`, which may be a `res://` or `user://` path.
+fn write_report(path: &GString, report: &str) {
+    let path = ProjectSettings::singleton()
+        .globalize_path(path)
+        .to_string();
+    match std::fs::write(&path, report) {
+        Ok(()) => println!("  Report written to {path}."),
+        Err(err) => println!("{FMT_RED}  Failed to write report to {path}: {err}{FMT_END}"),
+    }
+}
+
 fn get_property(test: &Variant, property: &str) -> String {
     test.call("get", &[property.to_variant()]).to::<String>()
 }
@@ -543,7 +795,8 @@ fn collect_rust_tests(filters: &[String]) -> RustTestCases {
 }
 
 #[must_use]
-enum TestOutcome {
+#[derive(Debug, Clone, Copy)]
+pub(super) enum TestOutcome {
     Passed,
     Failed,
     Skipped,
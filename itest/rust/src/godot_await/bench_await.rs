@@ -0,0 +1,70 @@
+use futures_util::future::join_all;
+
+use godot::builtin::Signal;
+use godot::classes::{Button, Engine, SceneTree};
+use godot::obj::NewAlloc;
+
+use godot_await::future::{or, zip};
+use godot_await::prelude::*;
+
+use crate::framework::{async_bench, count_polls};
+
+const FUTURES: usize = 1000;
+
+async fn signal_future_create_resolve() {
+    let mut button = Button::new_alloc();
+    let futures = (0..FUTURES)
+        .map(|_| count_polls(button.pressed()))
+        .collect::<Vec<_>>();
+
+    // Signal futures are connected on creation, so they resolve even though they are only awaited afterwards.
+    button.emit_signal("pressed", &[]);
+    join_all(futures).await;
+
+    button.free();
+}
+
+async_bench!(signal_future_create_resolve, repetitions = FUTURES);
+
+async fn zip_or_polling() {
+    let mut button = Button::new_alloc();
+    let futures = (0..FUTURES)
+        .map(|_| {
+            count_polls(or(
+                zip(button.button_down(), button.button_up()),
+                button.pressed(),
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    button.emit_signal("button_down", &[]);
+    button.emit_signal("button_up", &[]);
+    join_all(futures).await;
+
+    button.free();
+}
+
+async_bench!(zip_or_polling, repetitions = FUTURES);
+
+async fn concurrent_wait_timers() {
+    join_all((0..FUTURES).map(|_| count_polls(wait_pooled(0.05)))).await;
+}
+
+async_bench!(concurrent_wait_timers, repetitions = FUTURES);
+
+// Baseline for `concurrent_wait_timers`: one `SceneTreeTimer` and signal future per wait, as `wait()` does.
+async fn concurrent_scene_tree_timers() {
+    let mut tree = Engine::singleton()
+        .get_main_loop()
+        .expect("main loop exists")
+        .cast::<SceneTree>();
+    let futures = (0..FUTURES)
+        .map(|_| {
+            let timer = tree.create_timer(0.05).expect("timer created");
+            count_polls(Signal::from_object_signal(&timer, "timeout").to_future::<()>())
+        })
+        .collect::<Vec<_>>();
+    join_all(futures).await;
+}
+
+async_bench!(concurrent_scene_tree_timers, repetitions = FUTURES);
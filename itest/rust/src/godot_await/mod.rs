@@ -1,6 +1,10 @@
 #[cfg(since_api = "4.2")]
+mod bench_await;
+#[cfg(since_api = "4.2")]
 mod test_classes;
 #[cfg(since_api = "4.2")]
 mod test_future;
 #[cfg(since_api = "4.2")]
 mod test_futures_util;
+#[cfg(all(since_api = "4.2", feature = "await-instrumentation"))]
+mod test_instrumentation;
@@ -1,15 +1,39 @@
-use std::time::Instant;
+use std::cell::Cell;
+use std::pin::pin;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 
-use godot::classes::{Button, Engine, Node, Node2D, RefCounted, SceneTree};
-use godot::obj::{Base, Gd, NewAlloc};
+use futures_util::FutureExt as _;
+use godot::builtin::{
+    Callable, GString, PackedByteArray, PackedInt32Array, PackedVector2Array, Signal, StringName,
+    Variant, Vector2, Vector3,
+};
+use godot::classes::audio_stream_wav::Format;
+use godot::classes::item_list::SelectMode;
+use godot::classes::node::ProcessMode;
+use godot::classes::tween::TransitionType;
+use godot::classes::{
+    AudioServer, AudioStreamPlayer, AudioStreamWav, Button, Engine, ItemList, LineEdit,
+    NavigationAgent2D, NavigationAgent3D, NavigationPolygon, NavigationRegion2D, Node, Node2D,
+    OptionButton, PopupMenu, ProgressBar, RefCounted, SceneTree, TabBar, TabContainer, Timer, Tree,
+    VisibleOnScreenNotifier2D, Window,
+};
+use godot::meta::ToGodot;
+use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{godot_api, GodotClass};
 use godot::task;
 use godot::task::TaskHandle;
 
 // use godot_await::futures::{or, zip, FutureExt};
+use godot_await::classes::navigation_agent::NavigationResult;
+use godot_await::classes::tween::{TweenExt, TweenKilled};
+use godot_await::future::{or, zip};
 use godot_await::prelude::*;
+use godot_await::wait::{tick_realtime, wait_realtime_signal, LeftTree};
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{
+    frame_count, frames_for, itest, itest_timeout, measure_frames, TestContext,
+};
 
 #[derive(GodotClass)]
 #[class(init)]
@@ -29,7 +53,7 @@ impl AsyncRefCounted {
     fn u32_signal(value: u32);
 }
 
-fn get_tree() -> Gd<SceneTree> {
+pub(super) fn get_tree() -> Gd<SceneTree> {
     Engine::singleton()
         .get_main_loop()
         .expect("ERR(godot_await):get MainLoop failed")
@@ -40,24 +64,150 @@ fn get_tree() -> Gd<SceneTree> {
 #[itest(async)]
 fn wait_test() -> TaskHandle {
     task::spawn(async move {
+        let ((), elapsed) = measure_frames(|| wait(0.5)).await;
+        assert_eq!(elapsed.frames, frames_for(0.5));
+        assert_eq!(elapsed.seconds(), 0.5);
+
+        let ((), elapsed) =
+            measure_frames(|| wait_ex(&mut get_tree(), 0.1).process_always(false).done()).await;
+        assert_eq!(elapsed.frames, frames_for(0.1));
+
+        let ((), elapsed) = measure_frames(|| wait_pooled(0.5)).await;
+        assert_eq!(elapsed.frames, frames_for(0.5));
+
+        let ((), elapsed) = measure_frames(|| {
+            wait_ex(&mut get_tree(), 0.1)
+                .process_always(false)
+                .done_pooled()
+        })
+        .await;
+        assert_eq!(elapsed.frames, frames_for(0.1));
+    })
+}
+
+// Four timers of 1.2s in total, with room for the frames spent awaiting them.
+itest_timeout!(wait_test, 2.0);
+
+#[itest(async)]
+fn wait_pause_test() -> TaskHandle {
+    task::spawn(async move {
+        let mut tree = get_tree();
+        let mut pausable = wait_ex(&mut tree, 0.05).process_always(false).done_pooled();
+
+        tree.set_pause(true);
+        wait(0.1).await;
+        assert_eq!((&mut pausable).now_or_never(), None);
+
+        tree.set_pause(false);
+        pausable.await;
+    })
+}
+
+#[itest(async)]
+fn wait_after_node_process_test(test_context: &TestContext) -> TaskHandle {
+    // A `Timer` node times out during node processing, so the task resumes after the scheduler node processed.
+    let mut timer = Timer::new_alloc();
+    timer.set_one_shot(true);
+    timer.set_wait_time(0.05);
+    test_context.scene_tree.clone().add_child(&timer);
+
+    task::spawn(async move {
+        timer.start();
+        timer.timeout().await;
+        let start = frame_count();
+        wait_pooled(0.1).await;
+        let pooled = frame_count() - start;
+
+        timer.start();
+        timer.timeout().await;
+        let start = frame_count();
+        let mut native = get_tree().create_timer(0.1).expect("create SceneTreeTimer");
+        let fired = Rc::new(Cell::new(0));
+        let fired_ref = fired.clone();
+        native.connect(
+            "timeout",
+            &Callable::from_local_fn("native_timer_fired", move |_| {
+                fired_ref.set(frame_count());
+                Ok(Variant::nil())
+            }),
+        );
+        Signal::from_object_signal(&native, "timeout")
+            .to_future::<()>()
+            .await;
+
+        assert_eq!(pooled, fired.get() - start);
+        timer.queue_free();
+    })
+}
+
+#[itest(async)]
+fn node_wait_test(test_context: &TestContext) -> TaskHandle {
+    let mut menu = Node::new_alloc();
+    menu.set_process_mode(ProcessMode::WHEN_PAUSED);
+    let mut actor = Node::new_alloc();
+    actor.set_process_mode(ProcessMode::PAUSABLE);
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(&menu);
+    tree.add_child(&actor);
+
+    task::spawn(async move {
+        let mut scene_tree = get_tree();
+        scene_tree.set_pause(true);
+        let mut actor_wait = actor.wait(0.05);
+        assert_eq!(menu.wait(0.1).await, Ok(()));
+        assert_eq!((&mut actor_wait).now_or_never(), None);
+
+        scene_tree.set_pause(false);
+        assert_eq!(actor_wait.await, Ok(()));
+
+        let left = actor.wait(1.0);
+        actor.queue_free();
+        assert_eq!(left.await, Err(LeftTree));
+        menu.queue_free();
+    })
+}
+
+#[itest(async)]
+fn wait_realtime_test() -> TaskHandle {
+    task::spawn(async move {
+        let tree = get_tree();
+        // Headless runs draw no frames, so tick by hand.
+        let ticker = async {
+            loop {
+                tree.process_frame().await;
+                tick_realtime();
+            }
+        };
+
         let start = Instant::now();
-        wait(0.5).await;
-        println!("wait elasp sec:{}", (Instant::now() - start).as_secs_f32());
-        assert!((Instant::now() - start).as_secs_f32() >= 0.4);
+        wait_realtime(0.05).or(ticker).await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    })
+}
+
+// 50ms of real time span an unknown number of frames, so the budget is in real time too.
+itest_timeout!(wait_realtime_test, realtime 1.0);
+
+#[itest(async)]
+fn wait_without_tree_test() -> TaskHandle {
+    task::spawn(async move {
+        let tree = get_tree();
+        let ticker = async {
+            loop {
+                tree.process_frame().await;
+                tick_realtime();
+            }
+        };
 
+        // What `wait()` returns when the main loop is not a `SceneTree`.
         let start = Instant::now();
-        wait_ex(&mut get_tree(), 0.1)
-            .process_always(false)
-            .done()
-            .await;
-        println!(
-            "wait_ex elasp sec:{}",
-            (Instant::now() - start).as_secs_f32()
-        );
-        assert!((Instant::now() - start).as_secs_f32() >= 0.09);
+        wait_realtime_signal(0.05).or(ticker).await;
+        assert!(start.elapsed() >= Duration::from_millis(50));
     })
 }
 
+itest_timeout!(wait_without_tree_test, realtime 1.0);
+
 #[itest(async)]
 fn node_test(test_context: &TestContext) -> TaskHandle {
     let node = Node::new_alloc();
@@ -80,6 +230,413 @@ fn node_test(test_context: &TestContext) -> TaskHandle {
     task_handle
 }
 
+#[itest(async)]
+fn line_edit_prompt_test(test_context: &TestContext) -> TaskHandle {
+    let mut line_edit = LineEdit::new_alloc();
+    test_context.scene_tree.clone().add_child(&line_edit);
+    let prompt = line_edit.prompt();
+    assert!(line_edit.has_focus());
+
+    let line_edit_ref = line_edit.clone();
+    let task_handle = task::spawn(async move {
+        assert_eq!(prompt.await, GString::from("hello"));
+        line_edit_ref.free();
+    });
+
+    line_edit.emit_signal("text_submitted", &["hello".to_variant()]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn option_button_choose_test() -> TaskHandle {
+    let mut option_button = OptionButton::new_alloc();
+    let choice = option_button.choose();
+
+    let option_button_ref = option_button.clone();
+    let task_handle = task::spawn(async move {
+        assert_eq!(choice.await, 2);
+        option_button_ref.free();
+    });
+
+    option_button.emit_signal("item_selected", &[2.to_variant()]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn item_list_choose_multi_test() -> TaskHandle {
+    let mut item_list = ItemList::new_alloc();
+    item_list.set_select_mode(SelectMode::MULTI);
+    for item in ["knight", "archer", "mage"] {
+        item_list.add_item(item);
+    }
+    item_list.select_ex(0).single(false).done();
+    item_list.select_ex(2).single(false).done();
+    let party = item_list.choose_multi();
+
+    let item_list_ref = item_list.clone();
+    let task_handle = task::spawn(async move {
+        assert_eq!(party.await, vec![0, 2]);
+        item_list_ref.free();
+    });
+
+    item_list.emit_signal("item_activated", &[2.to_variant()]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn popup_menu_choose_test() -> TaskHandle {
+    let mut menu = PopupMenu::new_alloc();
+    let chosen = menu.choose();
+    let dismissed_menu = PopupMenu::new_alloc();
+    let dismissed = dismissed_menu.choose();
+
+    let menu_ref = menu.clone();
+    let dismissed_ref = dismissed_menu.clone();
+    let task_handle = task::spawn(async move {
+        assert_eq!(chosen.await, Some(7));
+        assert_eq!(dismissed.await, None);
+        menu_ref.free();
+        dismissed_ref.free();
+    });
+
+    // Pressing an item hides the menu first, then emits `id_pressed`.
+    menu.emit_signal("popup_hide", &[]);
+    menu.emit_signal("id_pressed", &[7.to_variant()]);
+    dismissed_menu.clone().emit_signal("popup_hide", &[]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn tree_test() -> TaskHandle {
+    let mut tree = Tree::new_alloc();
+    let mut root = tree.create_item().expect("create root item");
+    root.create_child();
+    let root_id = root.instance_id();
+    let collapsed = tree.item_collapsed();
+    let selected = tree.item_selected();
+
+    // `TreeItem::set_collapsed` emits `item_collapsed` on its tree.
+    root.set_collapsed(true);
+    tree.emit_signal("item_selected", &[]);
+    let freed = tree.item_selected_fallible();
+    tree.free();
+
+    task::spawn(async move {
+        let (item,) = collapsed.await;
+        assert_eq!(item.instance_id(), root_id);
+        selected.await;
+        assert!(freed.await.is_err());
+    })
+}
+
+#[itest(async)]
+fn tab_bar_choose_test() -> TaskHandle {
+    let mut tab_bar = TabBar::new_alloc();
+    for tab in ["inventory", "map", "quests"] {
+        tab_bar.add_tab_ex().title(tab).done();
+    }
+    let choice = tab_bar.choose();
+    let clicked = tab_bar.tab_clicked();
+
+    let tab_bar_ref = tab_bar.clone();
+    let task_handle = task::spawn(async move {
+        assert_eq!(choice.await, 2);
+        assert_eq!(clicked.await, (1,));
+        tab_bar_ref.free();
+    });
+
+    tab_bar.set_current_tab(2);
+    tab_bar.emit_signal("tab_clicked", &[1.to_variant()]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn tab_container_choose_test() -> TaskHandle {
+    let mut tab_container = TabContainer::new_alloc();
+    let choice = tab_container.choose();
+
+    tab_container.emit_signal("tab_changed", &[1.to_variant()]);
+    let freed = tab_container.choose_fallible();
+    tab_container.free();
+
+    task::spawn(async move {
+        assert_eq!(choice.await, 1);
+        assert!(freed.await.is_err());
+    })
+}
+
+#[itest(async)]
+fn range_value_reaches_test() -> TaskHandle {
+    let mut loading_bar = ProgressBar::new_alloc();
+    let mut cooldown = ProgressBar::new_alloc();
+    cooldown.set_value(100.0);
+
+    let loaded = loading_bar.wait_value_reaches(50.0);
+    let passed = loading_bar.wait_value_reaches(20.0);
+    let ready = cooldown.wait_value_reaches(0.0);
+    let already = cooldown.wait_value_reaches(100.0);
+
+    let bars = [loading_bar.clone(), cooldown.clone()];
+    let task_handle = task::spawn(async move {
+        assert_eq!(already.await, 100.0);
+        assert_eq!(loaded.await, 60.0);
+        // Created below 20, and first polled once the value went past it.
+        assert_eq!(passed.await, 60.0);
+        assert_eq!(ready.await, 0.0);
+        for bar in bars {
+            bar.free();
+        }
+    });
+
+    loading_bar.set_value(30.0);
+    loading_bar.set_value(60.0);
+    cooldown.set_value(0.0);
+
+    task_handle
+}
+
+#[itest(async)]
+fn navigation_agent_move_to_test() -> TaskHandle {
+    let mut agent_2d = NavigationAgent2D::new_alloc();
+    let mut agent_3d = NavigationAgent3D::new_alloc();
+    let mut freed_agent = NavigationAgent3D::new_alloc();
+
+    let target = Vector2::new(64.0, 32.0);
+    // Nothing moves the agents, so navigation finishes short of the targets.
+    let unreachable = agent_2d.move_to(target);
+    let velocity = agent_2d.velocity_computed();
+    let interrupted = agent_3d.move_to(Vector3::new(1.0, 0.0, 1.0));
+    let freed = freed_agent.move_to(Vector3::ONE);
+    assert_eq!(agent_2d.get_target_position(), target);
+
+    let agents = (agent_2d.clone(), agent_3d.clone());
+    let task_handle = task::spawn(async move {
+        assert_eq!(unreachable.await, NavigationResult::Unreachable);
+        assert_eq!(velocity.await, (Vector2::new(1.0, 0.0),));
+        assert_eq!(interrupted.await, NavigationResult::Interrupted);
+        assert_eq!(freed.await, NavigationResult::Interrupted);
+
+        // A new target only shows in a path update, without the first navigation finishing.
+        let mut agent_3d = agents.1.clone();
+        let retargeted = agent_3d.move_to(Vector3::ONE);
+        let (result, ()) = zip(retargeted, async {
+            wait(0.05).await;
+            agent_3d.set_target_position(Vector3::ZERO);
+            agent_3d.emit_signal("path_changed", &[]);
+        })
+        .await;
+        assert_eq!(result, NavigationResult::Interrupted);
+
+        agents.0.free();
+        agents.1.free();
+    });
+
+    agent_2d.emit_signal("navigation_finished", &[]);
+    agent_2d.emit_signal("velocity_computed", &[Vector2::new(1.0, 0.0).to_variant()]);
+    agent_3d.set_target_position(Vector3::new(-1.0, 0.0, 1.0));
+    agent_3d.emit_signal("navigation_finished", &[]);
+    freed_agent.free();
+
+    task_handle
+}
+
+#[itest(async)]
+fn navigation_agent_reached_test(test_context: &TestContext) -> TaskHandle {
+    let mut polygon = NavigationPolygon::new_gd();
+    let vertices: PackedVector2Array = [
+        Vector2::new(-128.0, -128.0),
+        Vector2::new(128.0, -128.0),
+        Vector2::new(128.0, 128.0),
+        Vector2::new(-128.0, 128.0),
+    ]
+    .into_iter()
+    .collect();
+    polygon.set_vertices(&vertices);
+    polygon.add_polygon(&[0, 1, 2, 3].into_iter().collect::<PackedInt32Array>());
+    let mut region = NavigationRegion2D::new_alloc();
+    region.set_navigation_polygon(&polygon);
+    let mut body = Node2D::new_alloc();
+    let agent = NavigationAgent2D::new_alloc();
+    body.add_child(&agent);
+    let mut tree = test_context.scene_tree.clone();
+    tree.add_child(&region);
+    tree.add_child(&body);
+
+    task::spawn(async move {
+        // The navigation map picks up the region on its next sync.
+        get_tree().physics_frame().await;
+        get_tree().physics_frame().await;
+
+        let target = Vector2::new(64.0, 32.0);
+        let moving = agent.move_to(target);
+        let mut body_ref = body.clone();
+        let mut agent_ref = agent.clone();
+        let (result, ()) = zip(moving, async move {
+            while !agent_ref.is_navigation_finished() {
+                get_tree().physics_frame().await;
+                let next = agent_ref.get_next_path_position();
+                body_ref.set_global_position(next);
+            }
+        })
+        .await;
+        assert_eq!(result, NavigationResult::Reached);
+
+        // Already there, so no navigation is left to finish.
+        assert_eq!(
+            agent.move_to(target).now_or_never(),
+            Some(NavigationResult::Reached)
+        );
+
+        body.free();
+        region.free();
+    })
+}
+
+#[itest(async)]
+fn visible_on_screen_test() -> TaskHandle {
+    // Never drawn, so the notifier is off screen.
+    let mut notifier = VisibleOnScreenNotifier2D::new_alloc();
+    assert!(!notifier.is_on_screen());
+
+    assert_eq!(notifier.wait_off_screen().now_or_never(), Some(()));
+    let on_screen = notifier.wait_on_screen();
+
+    let notifier_ref = notifier.clone();
+    let task_handle = task::spawn(async move {
+        on_screen.await;
+        notifier_ref.free();
+    });
+
+    notifier.emit_signal("screen_entered", &[]);
+
+    task_handle
+}
+
+#[itest(async)]
+fn audio_test() -> TaskHandle {
+    let mut server = AudioServer::singleton();
+    let layout_changed = server.bus_layout_changed();
+    let renamed = server.bus_renamed();
+
+    // Without a stream, the player stops right away.
+    let player = AudioStreamPlayer::new_alloc();
+    let played = player.play_and_wait(0.0);
+    let freed_player = AudioStreamPlayer::new_alloc();
+    let position = freed_player.wait_playback_position_fallible(10.0);
+
+    let player_ref = player.clone();
+    let task_handle = task::spawn(async move {
+        layout_changed.await;
+        let (bus_index, _, new_name) = renamed.await;
+        assert_eq!(new_name, StringName::from("GodotAwaitTest"));
+        AudioServer::singleton().remove_bus(bus_index as i32);
+
+        played.await;
+        assert!(!player_ref.is_playing());
+        player_ref.free();
+        assert!(position.await.is_err());
+    });
+
+    let bus_index = server.get_bus_count();
+    server.add_bus();
+    server.set_bus_name(bus_index, "GodotAwaitTest");
+    freed_player.free();
+
+    task_handle
+}
+
+#[itest(async)]
+fn audio_paused_test(test_context: &TestContext) -> TaskHandle {
+    // One second of silence, so the playback is still going when it is stopped.
+    let mut stream = AudioStreamWav::new_gd();
+    stream.set_format(Format::FORMAT_8_BITS);
+    stream.set_mix_rate(8000);
+    stream.set_data(&std::iter::repeat_n(0u8, 8000).collect::<PackedByteArray>());
+    let mut player = AudioStreamPlayer::new_alloc();
+    player.set_stream(&stream);
+    test_context.scene_tree.clone().add_child(&player);
+
+    task::spawn(async move {
+        let mut played = pin!(player.play_and_wait(0.0));
+
+        player.set_stream_paused(true);
+        let still_paused = or(
+            async {
+                played.as_mut().await;
+                false
+            },
+            async {
+                wait(0.1).await;
+                true
+            },
+        )
+        .await;
+        assert!(still_paused, "stream_paused");
+        player.set_stream_paused(false);
+
+        let mut tree = get_tree();
+        tree.set_pause(true);
+        let still_paused = or(
+            async {
+                played.as_mut().await;
+                false
+            },
+            async {
+                wait(0.1).await;
+                true
+            },
+        )
+        .await;
+        tree.set_pause(false);
+        assert!(still_paused, "tree paused");
+
+        player.stop();
+        played.await;
+        player.queue_free();
+    })
+}
+
+#[itest(async)]
+fn window_close_requested_test(test_context: &TestContext) -> TaskHandle {
+    let mut window = Window::new_alloc();
+    test_context.scene_tree.clone().add_child(&window);
+    window.show();
+    let closed = window.close_on_request();
+    let mut dialog = Window::new_alloc();
+    test_context.scene_tree.clone().add_child(&dialog);
+    dialog.show();
+    let requested = dialog.wait_close_requested();
+    let mut root = get_tree().get_root().expect("scene tree has a root");
+    let root_closed = root.close_on_request();
+
+    let window_ref = window.clone();
+    let dialog_ref = dialog.clone();
+    let root_ref = root.clone();
+    let task_handle = task::spawn(async move {
+        closed.await;
+        assert!(!window_ref.is_visible());
+        // A plain await leaves the decision to the caller.
+        requested.await;
+        assert!(dialog_ref.is_visible());
+        dialog_ref.free();
+        // Left as is, instead of failing to hide.
+        root_closed.await;
+        assert!(root_ref.is_visible());
+        window_ref.free();
+    });
+
+    window.emit_signal("close_requested", &[]);
+    dialog.emit_signal("close_requested", &[]);
+    root.emit_signal("close_requested", &[]);
+
+    task_handle
+}
+
 #[itest(async)]
 fn button_fallible_test() -> TaskHandle {
     let mut button = Button::new_alloc();
@@ -94,3 +651,33 @@ fn button_fallible_test() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn tween_property_async_test(test_context: &TestContext) -> TaskHandle {
+    let mut node = Node2D::new_alloc();
+    test_context.scene_tree.clone().add_child(&node);
+
+    task::spawn(async move {
+        let target = Vector2::new(10.0, 0.0);
+        let result = node
+            .tween_property_async("position", target, 0.1)
+            .trans(TransitionType::SINE)
+            .parallel()
+            .property("rotation", 1.0, 0.1)
+            .delay(0.05)
+            .await;
+        assert_eq!(result, Ok(()));
+        assert!((node.get_position() - target).length() < 0.001);
+
+        let builder = node.tween_property_async("position", Vector2::ZERO, 1.0);
+        builder.tween().kill();
+        assert_eq!(builder.await, Err(TweenKilled));
+
+        let tween = node
+            .tween_property_async("position", Vector2::ZERO, 1.0)
+            .tween();
+        let killed = tween.finished_or_killed();
+        node.queue_free();
+        assert_eq!(killed.await, Err(TweenKilled));
+    })
+}
@@ -1,14 +1,20 @@
-use godot::classes::Button;
+use godot::classes::{Button, Engine};
 use godot::meta::ToGodot;
 use godot::obj::NewAlloc;
 use godot::task;
 use godot::task::TaskHandle;
-use std::time::Instant;
+use std::cell::Cell;
+use std::rc::Rc;
 
-use godot_await::future::{or, zip};
+use godot_await::backend::{Clock, GodotClock, SignalSource};
+use godot_await::cancel::{abortable, Aborted, CancellationToken, Cancelled};
+use godot_await::future::{or, retry, retry_with_backoff, zip, Backoff};
+use godot_await::interval::{interval_ex, MissedTickBehavior};
 use godot_await::prelude::*;
+use godot_await::sequence::SequenceReport;
+use godot_await::{parallel, sequence};
 
-use crate::framework::itest;
+use crate::framework::{frames_for, itest, measure_frames};
 
 #[itest(async)]
 fn zip_test() -> TaskHandle {
@@ -29,17 +35,17 @@ fn zip_test() -> TaskHandle {
 #[itest(async)]
 fn or_test_1() -> TaskHandle {
     let mut button = Button::new_alloc();
-    let button_ref = button.clone();
-
-    let task_handle = task::spawn(async move {
-        let start = Instant::now();
-        or(wait(0.5), button_ref.button_down()).await;
-        assert!((Instant::now() - start).as_secs_f32() < 0.01);
-    });
 
-    button.emit_signal("button_down", &[]);
-
-    task_handle
+    task::spawn(async move {
+        let ((), elapsed) = measure_frames(|| {
+            let future = or(wait(0.5), button.button_down());
+            button.emit_signal("button_down", &[]);
+            future
+        })
+        .await;
+        assert_eq!(elapsed.frames, 1);
+        button.free();
+    })
 }
 
 #[itest(async)]
@@ -47,13 +53,9 @@ fn or_test_2() -> TaskHandle {
     let button = Button::new_alloc();
 
     task::spawn(async move {
-        let start = Instant::now();
-        or(button.button_down(), wait(0.1)).await;
-        println!(
-            "or_test_2 elasp sec:{}",
-            (Instant::now() - start).as_secs_f32()
-        );
-        assert!((Instant::now() - start).as_secs_f32() >= 0.09);
+        let ((), elapsed) = measure_frames(|| or(button.button_down(), wait(0.1))).await;
+        assert_eq!(elapsed.frames, frames_for(0.1));
+        button.free();
     })
 }
 
@@ -62,23 +64,24 @@ fn or_test_self_right() -> TaskHandle {
     let button = Button::new_alloc();
 
     task::spawn(async move {
-        let start = Instant::now();
-        button.button_down().or(wait(0.1)).await;
-        assert!((Instant::now() - start).as_secs_f32() > 0.09);
+        let ((), elapsed) = measure_frames(|| button.button_down().or(wait(0.1))).await;
+        assert_eq!(elapsed.frames, frames_for(0.1));
     })
 }
 #[itest(async)]
 fn or_test_self_left() -> TaskHandle {
     let mut button = Button::new_alloc();
-    let button_ref = button.clone();
 
-    let task_handle = task::spawn(async move {
-        let start = Instant::now();
-        button_ref.button_down().or(wait(0.1)).await;
-        assert!((Instant::now() - start).as_secs_f32() < 0.001);
-    });
-    button.emit_signal("button_down", &[]);
-    task_handle
+    task::spawn(async move {
+        let ((), elapsed) = measure_frames(|| {
+            let future = button.button_down().or(wait(0.1));
+            button.emit_signal("button_down", &[]);
+            future
+        })
+        .await;
+        assert_eq!(elapsed.frames, 1);
+        button.free();
+    })
 }
 
 #[itest(async)]
@@ -94,3 +97,242 @@ fn or_test_catch_unwind() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn with_cancel_test() -> TaskHandle {
+    let token = CancellationToken::new();
+    let child = token.child_token();
+
+    task::spawn(async move {
+        let (result, elapsed) = measure_frames(|| {
+            let future = wait(5.0).with_cancel(&child);
+            token.cancel();
+            future
+        })
+        .await;
+        assert_eq!(result, Err(Cancelled));
+        assert_eq!(elapsed.frames, 1);
+    })
+}
+
+#[itest(async)]
+fn or_cancelled_test() -> TaskHandle {
+    let button = Button::new_alloc();
+    let token = CancellationToken::new();
+    let token_ref = token.clone();
+
+    let task_handle = task::spawn(async move {
+        or(button.pressed(), token_ref.cancelled()).await;
+        assert!(token_ref.is_cancelled());
+        button.free();
+    });
+
+    token.cancel();
+    task_handle
+}
+
+#[itest(async)]
+fn abortable_test() -> TaskHandle {
+    let button = Button::new_alloc();
+    let (future, handle) = abortable(button.pressed());
+
+    let task_handle = task::spawn(async move {
+        assert_eq!(future.await, Err(Aborted));
+        button.free();
+    });
+
+    handle.abort();
+    task_handle
+}
+
+#[itest(async)]
+fn timed_test() -> TaskHandle {
+    task::spawn(async move {
+        let ((), elapsed) = wait(0.25).timed().await;
+        assert_eq!(elapsed, 0.25);
+
+        // At double speed, the wait takes half the real time.
+        Engine::singleton().set_time_scale(2.0);
+        let unscaled = Stopwatch::start_ex().ignore_time_scale(true).done();
+        let ((), elapsed) = wait(0.25).timed().await;
+        let real_time = unscaled.elapsed();
+        Engine::singleton().set_time_scale(1.0);
+
+        assert_eq!(elapsed, 0.25);
+        assert_eq!(real_time, 0.125);
+    })
+}
+
+#[itest(async)]
+fn interval_test() -> TaskHandle {
+    task::spawn(async move {
+        // The first tick resolves right away, the others stay on the 0.125s grid.
+        let stopwatch = Stopwatch::start();
+        let mut ticks = interval(0.125);
+        for _ in 0..4 {
+            ticks.tick().await;
+        }
+        assert_eq!(stopwatch.elapsed(), 0.375);
+
+        for (behavior, expected) in [
+            (MissedTickBehavior::Burst, 0.25),
+            (MissedTickBehavior::Delay, 0.375),
+            (MissedTickBehavior::Skip, 0.375),
+        ] {
+            let stopwatch = Stopwatch::start();
+            let mut ticks = interval_ex(0.125).missed_tick_behavior(behavior).done();
+            ticks.tick().await;
+            // Misses the tick at 0.125s.
+            wait(0.25).await;
+            ticks.tick().await;
+            ticks.tick().await;
+            assert_eq!(stopwatch.elapsed(), expected, "{behavior:?}");
+        }
+    })
+}
+
+#[itest(async)]
+fn retry_fallible_test() -> TaskHandle {
+    task::spawn(async move {
+        let mut attempts = 0;
+        let result = retry(2, || {
+            attempts += 1;
+            let mut button = Button::new_alloc();
+            let future = button.pressed_fallible();
+            button.call_deferred("free", &[]);
+            future
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 2);
+    })
+}
+
+#[itest(async)]
+fn retry_with_backoff_test() -> TaskHandle {
+    task::spawn(async move {
+        let attempts = Cell::new(0);
+        let (result, elapsed) = measure_frames(|| {
+            retry_with_backoff(Backoff::linear(0.05, 0.05), 3, || {
+                attempts.set(attempts.get() + 1);
+                let attempt = attempts.get();
+                async move {
+                    if attempt < 3 {
+                        Err(attempt)
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+        })
+        .await;
+
+        assert_eq!(result, Ok(3));
+        // 0.05s + 0.1s of backoff between the three attempts, on the game clock.
+        assert!(elapsed.frames >= frames_for(0.05) + frames_for(0.1));
+    })
+}
+
+#[itest(async)]
+fn sequence_macro_test() -> TaskHandle {
+    let mut button = Button::new_alloc();
+    let button_ref = button.clone();
+
+    task::spawn(async move {
+        wait(0.05).await;
+        button.emit_signal("button_down", &[]);
+        wait(0.05).await;
+        button.emit_signal("toggled", &[true.to_variant()]);
+    });
+
+    task::spawn(async move {
+        let ret = sequence!(button_ref.button_down(), button_ref.toggled()).await;
+        assert_eq!(ret, ((), (true,)));
+
+        let ret = parallel!(wait(0.05), async { 2 }, wait(0.1)).await;
+        assert_eq!(ret, ((), 2, ()));
+        button_ref.free();
+    })
+}
+
+#[itest(async)]
+fn sequence_skip_test() -> TaskHandle {
+    let button = Button::new_alloc();
+    let skip = CancellationToken::new();
+    let skipped_pressed = Rc::new(Cell::new(false));
+
+    task::spawn(async move {
+        let button_ref = button.clone();
+        let skipped_ref = skipped_pressed.clone();
+        let report = Sequence::new()
+            .step("first", || wait(0.05))
+            .step("pressed", move || button_ref.pressed())
+            .on_skip(move || skipped_ref.set(true))
+            .step("never", || wait(5.0))
+            .skip_on(&skip)
+            .run();
+
+        let (report, _) = zip(report, async {
+            wait(0.1).await;
+            skip.cancel();
+        })
+        .await;
+
+        assert_eq!(
+            report,
+            SequenceReport {
+                completed: vec!["first".to_string()],
+                skipped: vec!["pressed".to_string(), "never".to_string()],
+            }
+        );
+        assert!(skipped_pressed.get());
+        button.free();
+    })
+}
+
+#[itest(async)]
+fn sequence_fast_forward_test() -> TaskHandle {
+    task::spawn(async move {
+        let (report, elapsed) = measure_frames(|| {
+            Sequence::new()
+                .step("ready", || async {})
+                .step("wait", || wait(5.0))
+                .fast_forward(true)
+                .run()
+        })
+        .await;
+
+        assert_eq!(report.completed, ["ready"]);
+        assert_eq!(report.skipped, ["wait"]);
+        assert!(elapsed.frames < frames_for(5.0));
+    })
+}
+
+async fn toggle_after(
+    clock: impl Clock,
+    toggled: impl SignalSource<Output = (bool,)>,
+    delay: f64,
+) -> (bool, f64) {
+    let (on,) = toggled.next().await;
+    let start = clock.now();
+    clock.sleep(delay).await;
+    (on, clock.now() - start)
+}
+
+// The same flow runs on mocks in the unit tests of `godot_await::mock`.
+#[itest(async)]
+fn backend_test() -> TaskHandle {
+    let mut button = Button::new_alloc();
+    let button_ref = button.clone();
+    let task_handle = task::spawn(async move {
+        let (on, elapsed) = toggle_after(GodotClock, || button_ref.toggled(), 0.1).await;
+        assert!(on);
+        // `now()` reads the game clock the sleep advances with.
+        assert!(elapsed >= 0.1);
+        button_ref.free();
+    });
+    button.emit_signal("toggled", &[true.to_variant()]);
+
+    task_handle
+}
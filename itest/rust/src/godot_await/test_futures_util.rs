@@ -1,5 +1,3 @@
-use std::time::Instant;
-
 use futures_util::{join, pin_mut, select, FutureExt};
 
 use godot::classes::{Button, Engine, Node, SceneTree};
@@ -11,7 +9,7 @@ use godot::task::TaskHandle;
 // use godot_await::futures::{or, zip, FutureExt};
 use godot_await::prelude::*;
 
-use crate::framework::{itest, TestContext};
+use crate::framework::{frames_for, itest, measure_frames, TestContext};
 
 fn get_tree() -> Gd<SceneTree> {
     Engine::singleton()
@@ -24,21 +22,13 @@ fn get_tree() -> Gd<SceneTree> {
 #[itest(async)]
 fn wait_test() -> TaskHandle {
     task::spawn(async move {
-        let start = Instant::now();
-        wait(0.5).await;
-        println!("wait elasp sec:{}", (Instant::now() - start).as_secs_f32());
-        assert!((Instant::now() - start).as_secs_f32() >= 0.4);
-
-        let start = Instant::now();
-        wait_ex(&mut get_tree(), 0.1)
-            .process_always(false)
-            .done()
-            .await;
-        println!(
-            "wait_ex elasp sec:{}",
-            (Instant::now() - start).as_secs_f32()
-        );
-        assert!((Instant::now() - start).as_secs_f32() >= 0.09);
+        let ((), elapsed) = measure_frames(|| wait(0.5)).await;
+        assert_eq!(elapsed.frames, frames_for(0.5));
+        assert_eq!(elapsed.seconds(), 0.5);
+
+        let ((), elapsed) =
+            measure_frames(|| wait_ex(&mut get_tree(), 0.1).process_always(false).done()).await;
+        assert_eq!(elapsed.frames, frames_for(0.1));
     })
 }
 
@@ -0,0 +1,158 @@
+//! Tests of godot_await's `diagnostics`, `registry` and `editor` features, see the `await-instrumentation` feature.
+
+use godot::builtin::{varray, Dictionary};
+use godot::classes::{Button, Node, Timer};
+use godot::meta::ToGodot;
+use godot::obj::NewAlloc;
+use godot::task;
+use godot::task::TaskHandle;
+
+use godot_await::debugger::{deserialize, serialize, TaskInfo};
+use godot_await::prelude::*;
+use godot_await::registry::pending_awaits;
+
+use super::test_classes::get_tree;
+use crate::framework::{itest, TestContext};
+
+#[itest(async)]
+fn never_resolves_test(test_context: &TestContext) -> TaskHandle {
+    let mut node = Node::new_alloc();
+    test_context.scene_tree.clone().add_child(&node);
+
+    let stopped = Timer::new_alloc();
+    node.add_child(&stopped);
+    let mut started = Timer::new_alloc();
+    started.set_wait_time(0.05);
+    node.add_child(&started);
+
+    let mut tween = node.create_tween().expect("create Tween");
+    tween.tween_interval(1.0);
+    tween.set_loops();
+
+    task::spawn(async move {
+        assert!(stopped.timeout_fallible().await.is_err());
+        assert!(tween.finished_fallible().await.is_err());
+
+        // Started in the frame the future is created in, so not reported.
+        let timeout = started.timeout_fallible();
+        started.start();
+        assert!(timeout.await.is_ok());
+
+        tween.kill();
+        node.queue_free();
+    })
+}
+
+#[itest(async)]
+fn registry_test() -> TaskHandle {
+    let mut button = Button::new_alloc();
+    let button_ref = button.clone();
+    let id = button.instance_id();
+
+    let named = spawn_named("registry_test", async move {
+        button_ref.pressed().await;
+    });
+
+    let pending = pending_awaits();
+    let entry = pending
+        .iter()
+        .find(|pending| pending.object_id == id)
+        .expect("pressed() is registered");
+    assert_eq!(entry.task.as_deref(), Some("registry_test"));
+    assert_eq!(entry.class, "Button");
+    assert_eq!(entry.signal, "pressed");
+    assert!(entry.location.file().ends_with("test_classes.rs"));
+
+    button.emit_signal("pressed", &[]);
+    assert!(!pending_awaits()
+        .iter()
+        .any(|pending| pending.object_id == id));
+
+    task::spawn(async move {
+        wait(0.05).await;
+        assert!(!named.is_pending());
+        button.free();
+    })
+}
+
+#[itest]
+fn registry_dropped_future_test() {
+    let button = Button::new_alloc();
+    let id = button.instance_id();
+    let connections = button.get_signal_connection_list("pressed").len();
+
+    for _ in 0..100 {
+        // e.g. the losing side of `or`.
+        drop(button.pressed());
+    }
+
+    assert!(!pending_awaits()
+        .iter()
+        .any(|pending| pending.object_id == id));
+    assert_eq!(
+        button.get_signal_connection_list("pressed").len(),
+        connections
+    );
+    button.free();
+}
+
+#[itest(async)]
+fn debugger_serialization_test() -> TaskHandle {
+    let mut button = Button::new_alloc();
+    button.set_name("DebuggerButton");
+    get_tree()
+        .get_root()
+        .expect("ERR(godot_await):get root failed")
+        .add_child(&button);
+    let button_ref = button.clone();
+    let id = button.instance_id();
+
+    let named = spawn_named("debugger_test", async move {
+        button_ref.pressed().await;
+    });
+
+    let mut data = serialize(&pending_awaits());
+    data.push(&Dictionary::new().to_variant());
+    data.push(&varray![1, 2].to_variant());
+
+    let tasks = deserialize(&data);
+    let task: &TaskInfo = tasks
+        .iter()
+        .find(|task| task.object_id == id.to_i64())
+        .expect("pressed() is serialized");
+    assert_eq!(task.task, "debugger_test");
+    assert_eq!(task.class, "Button");
+    assert_eq!(task.signal, "pressed");
+    assert_eq!(task.node_path, "/root/DebuggerButton");
+    assert!(task.location.contains("test_classes.rs"));
+    assert!(task.age_sec >= 0.0);
+    assert_eq!(tasks.len(), data.len() - 2);
+    assert_eq!(
+        TaskInfo::from_dictionary(&task.to_dictionary()).as_ref(),
+        Some(task)
+    );
+
+    button.emit_signal("pressed", &[]);
+    task::spawn(async move {
+        wait(0.05).await;
+        assert!(!named.is_pending());
+        button.queue_free();
+    })
+}
+
+#[itest(async)]
+fn registry_wait_test() -> TaskHandle {
+    task::spawn(async move {
+        let waiting = wait_ex(&mut get_tree(), 0.05)
+            .process_always(false)
+            .done_pooled();
+        assert!(pending_awaits()
+            .iter()
+            .any(|pending| pending.signal == "wait(0.05s)"));
+
+        waiting.await;
+        assert!(!pending_awaits()
+            .iter()
+            .any(|pending| pending.signal == "wait(0.05s)"));
+    })
+}